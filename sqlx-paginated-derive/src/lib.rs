@@ -0,0 +1,744 @@
+//! Derive macros for [sqlx-paginated](https://docs.rs/sqlx-paginated).
+//!
+//! This crate is re-exported by `sqlx-paginated` with the `derive` feature and shouldn't be
+//! depended on directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Field, Fields, GenericArgument, LitInt,
+    LitStr, PathArguments, Type,
+};
+
+/// Implements `PaginatedModel`, declaring the columns the query builders accept.
+///
+/// # Field Attributes
+///
+/// - `#[paginated(sortable, filterable, searchable)]` - What the field can be used for,
+///   fields without any of them can be used for everything
+/// - `#[paginated(column = "u.email")]` - SQL column the field maps to, the field name by default
+/// - `#[paginated(sql_type = "text")]` - SQL type of the column, inferred from the field type
+///   when omitted. One of `text`, `smallint`, `integer`, `bigint`, `real`, `double`, `numeric`,
+///   `boolean`, `uuid`, `date`, `time`, `timestamp`, `timestamptz` or `json`
+/// - `#[paginated(search_mode = "exact")]` - How the search text matches the column, the
+///   search mode of the query parameters by default. One of `contains`, `starts_with`,
+///   `ends_with`, `exact` or `whole_word`
+/// - `#[paginated(search_weight = 3)]` - Weight of a search match in the column towards the
+///   relevance pages without an explicit sort are ordered by
+/// - `#[paginated(skip)]`, `#[serde(skip)]` or `#[serde(skip_serializing)]` - Excludes the field
+/// - `#[paginated(flatten)]` or `#[serde(flatten)]` - Includes the columns of a nested
+///   `PaginatedModel`
+///
+/// Fields are named after their `#[serde(rename = "...")]` or
+/// `#[serde(rename(serialize = "..."))]` when present, or else after the container
+/// `#[serde(rename_all = "...")]`, matching the names used by the query parameters. The
+/// `serde` attributes belong to `#[derive(Serialize)]`, which the model needs for them to be
+/// accepted.
+///
+/// The output name of a field, the column `FromRow` reads it from, is likewise read from
+/// `#[sqlx(rename = "...")]` and the container `#[sqlx(rename_all = "...")]`.
+///
+/// Filter values of columns with a known SQL type are parsed and cast to that type, fields of
+/// other types keep the cast guessed from the filter value.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::{Paginated, PaginatedModel};
+///
+/// #[derive(Paginated)]
+/// struct User {
+///     #[paginated(sortable, filterable, searchable, search_mode = "exact")]
+///     id: i64,
+///     #[paginated(filterable, searchable, column = "u.email", search_weight = 2)]
+///     email: String,
+///     #[paginated(skip)]
+///     password_hash: String,
+/// }
+///
+/// let registry = User::column_registry();
+/// assert_eq!(registry.column_of("email"), "u.email");
+/// assert!(!registry.is_sortable("email"));
+/// assert!(!registry.contains("password_hash"));
+/// ```
+///
+/// `QueryBuilder` renders the conditions on `u.email`, for queries joining `users u`, while
+/// `PaginatedQueryBuilder` filters the result of its query on the `email` column it selects,
+/// named after the output name of the field.
+#[proc_macro_derive(Paginated, attributes(paginated))]
+pub fn derive_paginated(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_paginated(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct ColumnAttributes {
+    name: Option<String>,
+    output_name: Option<String>,
+    column: Option<String>,
+    sql_type: Option<String>,
+    search_mode: Option<String>,
+    search_weight: Option<u32>,
+    sortable: bool,
+    filterable: bool,
+    searchable: bool,
+    skip: bool,
+    flatten: bool,
+}
+
+fn expand_paginated(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Paginated can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Paginated can only be derived for structs",
+            ))
+        }
+    };
+
+    let rename_all = parse_rename_all(&input.attrs, "serde")?;
+    let output_rename_all = parse_rename_all(&input.attrs, "sqlx")?;
+    let mut statements = Vec::new();
+    for field in fields {
+        let attributes = parse_column_attributes(field)?;
+        if attributes.skip {
+            continue;
+        }
+
+        if attributes.flatten {
+            let ty = &field.ty;
+            statements.push(quote! {
+                columns.extend(
+                    <#ty as ::sqlx_paginated::PaginatedModel>::column_registry()
+                        .columns()
+                        .iter()
+                        .cloned(),
+                );
+            });
+            continue;
+        }
+
+        let name = attributes
+            .name
+            .clone()
+            .unwrap_or_else(|| rename_field(&field_name(field), rename_all));
+        let column = attributes.column.clone().unwrap_or_else(|| name.clone());
+        let output_name = attributes
+            .output_name
+            .clone()
+            .unwrap_or_else(|| rename_field(&field_name(field), output_rename_all));
+        let all = !attributes.sortable && !attributes.filterable && !attributes.searchable;
+        let sortable = all || attributes.sortable;
+        let filterable = all || attributes.filterable;
+        let searchable = all || attributes.searchable;
+        let column_type = match attributes
+            .sql_type
+            .as_deref()
+            .or_else(|| infer_sql_type(&field.ty))
+        {
+            Some(sql_type) => {
+                let variant = syn::Ident::new(
+                    column_type_variant(sql_type),
+                    proc_macro2::Span::call_site(),
+                );
+                quote! { ::std::option::Option::Some(::sqlx_paginated::ColumnType::#variant) }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
+        let search_mode = match attributes.search_mode.as_deref() {
+            Some(search_mode) => {
+                let variant = syn::Ident::new(
+                    search_mode_variant(search_mode),
+                    proc_macro2::Span::call_site(),
+                );
+                quote! { ::std::option::Option::Some(::sqlx_paginated::QuerySearchMode::#variant) }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
+        let search_weight = match attributes.search_weight {
+            Some(search_weight) => quote! { ::std::option::Option::Some(#search_weight) },
+            None => quote! { ::std::option::Option::None },
+        };
+
+        statements.push(quote! {
+            columns.push(::sqlx_paginated::PaginatedColumn {
+                name: ::std::string::String::from(#name),
+                column: ::std::string::String::from(#column),
+                column_type: #column_type,
+                sortable: #sortable,
+                filterable: #filterable,
+                searchable: #searchable,
+                search_mode: #search_mode,
+                search_weight: #search_weight,
+                output_name: ::std::option::Option::Some(::std::string::String::from(#output_name)),
+            });
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let build_registry = quote! {
+        let mut columns = ::std::vec::Vec::new();
+        #(#statements)*
+        ::sqlx_paginated::ColumnRegistry::new(columns)
+    };
+
+    // Statics are shared by every instantiation of a generic function, so generic models
+    // build their registry on each call
+    let body = if input.generics.params.is_empty() {
+        quote! {
+            static REGISTRY: ::std::sync::OnceLock<::sqlx_paginated::ColumnRegistry> =
+                ::std::sync::OnceLock::new();
+
+            REGISTRY.get_or_init(|| { #build_registry }).clone()
+        }
+    } else {
+        build_registry
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::sqlx_paginated::PaginatedModel for #ident #ty_generics #where_clause {
+            fn column_registry() -> ::sqlx_paginated::ColumnRegistry {
+                #body
+            }
+        }
+    })
+}
+
+fn field_name(field: &Field) -> String {
+    let ident = field
+        .ident
+        .as_ref()
+        .expect("named fields always have an identifier")
+        .to_string();
+
+    ident
+        .strip_prefix("r#")
+        .map(str::to_string)
+        .unwrap_or(ident)
+}
+
+/// Case conventions accepted by `#[serde(rename_all = "...")]`.
+const RENAME_RULES: &[&str] = &[
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Names a field the way serde does under a `rename_all` rule.
+fn rename_field(field: &str, rename_all: Option<&str>) -> String {
+    let pascal_case = || {
+        field
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect::<String>()
+    };
+
+    match rename_all {
+        Some("lowercase") => field.to_ascii_lowercase(),
+        Some("UPPERCASE") | Some("SCREAMING_SNAKE_CASE") => field.to_ascii_uppercase(),
+        Some("PascalCase") => pascal_case(),
+        Some("camelCase") => {
+            let pascal_case = pascal_case();
+            let mut chars = pascal_case.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+        Some("kebab-case") => field.replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => field.to_ascii_uppercase().replace('_', "-"),
+        _ => field.to_string(),
+    }
+}
+
+/// SQL types accepted by `#[paginated(sql_type = "...")]`, with their `ColumnType` variant.
+const SQL_TYPES: &[(&str, &str)] = &[
+    ("text", "Text"),
+    ("smallint", "SmallInt"),
+    ("integer", "Integer"),
+    ("bigint", "BigInt"),
+    ("real", "Real"),
+    ("double", "Double"),
+    ("numeric", "Numeric"),
+    ("boolean", "Boolean"),
+    ("uuid", "Uuid"),
+    ("date", "Date"),
+    ("time", "Time"),
+    ("timestamp", "Timestamp"),
+    ("timestamptz", "TimestampTz"),
+    ("json", "Json"),
+];
+
+fn column_type_variant(sql_type: &str) -> &'static str {
+    SQL_TYPES
+        .iter()
+        .find(|(name, _)| *name == sql_type)
+        .map(|(_, variant)| *variant)
+        .expect("SQL types are validated while parsing the attributes")
+}
+
+/// Search modes accepted by `#[paginated(search_mode = "...")]`, with their `QuerySearchMode` variant.
+const SEARCH_MODES: &[(&str, &str)] = &[
+    ("contains", "Contains"),
+    ("starts_with", "StartsWith"),
+    ("ends_with", "EndsWith"),
+    ("exact", "Exact"),
+    ("whole_word", "WholeWord"),
+];
+
+fn search_mode_variant(search_mode: &str) -> &'static str {
+    SEARCH_MODES
+        .iter()
+        .find(|(name, _)| *name == search_mode)
+        .map(|(_, variant)| *variant)
+        .expect("search modes are validated while parsing the attributes")
+}
+
+/// Infers the SQL type of a field from the last segment of its type, looking through `Option`.
+fn infer_sql_type(ty: &Type) -> Option<&'static str> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+
+    let sql_type = match segment.ident.to_string().as_str() {
+        "Option" => {
+            let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+                return None;
+            };
+            return match arguments.args.first()? {
+                GenericArgument::Type(inner) => infer_sql_type(inner),
+                _ => None,
+            };
+        }
+        "String" | "str" => "text",
+        "i8" | "i16" | "u8" => "smallint",
+        "i32" | "u16" => "integer",
+        "i64" | "u32" => "bigint",
+        "f32" => "real",
+        "f64" => "double",
+        "Decimal" | "BigDecimal" => "numeric",
+        "bool" => "boolean",
+        "Uuid" => "uuid",
+        "NaiveDate" | "Date" => "date",
+        "NaiveTime" | "Time" => "time",
+        "NaiveDateTime" | "PrimitiveDateTime" => "timestamp",
+        "DateTime" | "OffsetDateTime" => "timestamptz",
+        "Value" | "Json" | "JsonValue" => "json",
+        _ => return None,
+    };
+    Some(sql_type)
+}
+
+/// Reads the case convention of the fields from the `serde` or `sqlx` container attributes.
+fn parse_rename_all(attrs: &[Attribute], path: &str) -> syn::Result<Option<&'static str>> {
+    let mut rename_all = None;
+
+    for attribute in attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident(path))
+    {
+        attribute.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("rename_all") {
+                return skip_meta(&meta);
+            }
+
+            let mut parse_rule = |value: LitStr| -> syn::Result<()> {
+                let rule = RENAME_RULES
+                    .iter()
+                    .find(|rule| **rule == value.value())
+                    .ok_or_else(|| {
+                        syn::Error::new_spanned(&value, "unsupported rename_all rule")
+                    })?;
+                rename_all = Some(*rule);
+                Ok(())
+            };
+            if meta.input.peek(syn::Token![=]) {
+                parse_rule(meta.value()?.parse()?)
+            } else {
+                meta.parse_nested_meta(|nested| {
+                    let value = nested.value()?.parse::<LitStr>()?;
+                    match nested.path.is_ident("serialize") {
+                        true => parse_rule(value),
+                        false => Ok(()),
+                    }
+                })
+            }
+        })?;
+    }
+
+    Ok(rename_all)
+}
+
+/// Skips an attribute that doesn't change the columns, leaving it to serde or sqlx.
+fn skip_meta(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| {
+            if nested.input.peek(syn::Token![=]) {
+                nested.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+fn parse_column_attributes(field: &Field) -> syn::Result<ColumnAttributes> {
+    let mut attributes = ColumnAttributes::default();
+
+    for attribute in &field.attrs {
+        if attribute.path().is_ident("paginated") {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("sortable") {
+                    attributes.sortable = true;
+                } else if meta.path.is_ident("filterable") {
+                    attributes.filterable = true;
+                } else if meta.path.is_ident("searchable") {
+                    attributes.searchable = true;
+                } else if meta.path.is_ident("skip") {
+                    attributes.skip = true;
+                } else if meta.path.is_ident("flatten") {
+                    attributes.flatten = true;
+                } else if meta.path.is_ident("column") {
+                    attributes.column = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("sql_type") {
+                    let sql_type = meta.value()?.parse::<LitStr>()?;
+                    if !SQL_TYPES.iter().any(|(name, _)| *name == sql_type.value()) {
+                        return Err(syn::Error::new_spanned(sql_type, "unsupported sql_type"));
+                    }
+                    attributes.sql_type = Some(sql_type.value());
+                } else if meta.path.is_ident("search_mode") {
+                    let search_mode = meta.value()?.parse::<LitStr>()?;
+                    if !SEARCH_MODES
+                        .iter()
+                        .any(|(name, _)| *name == search_mode.value())
+                    {
+                        return Err(syn::Error::new_spanned(
+                            search_mode,
+                            "unsupported search_mode",
+                        ));
+                    }
+                    attributes.search_mode = Some(search_mode.value());
+                } else if meta.path.is_ident("search_weight") {
+                    let search_weight = meta.value()?.parse::<LitInt>()?;
+                    attributes.search_weight = Some(search_weight.base10_parse()?);
+                } else {
+                    return Err(meta.error("unsupported paginated attribute"));
+                }
+                Ok(())
+            })?;
+        } else if attribute.path().is_ident("serde") {
+            // Other serde attributes are left to serde, only the ones changing the columns are read
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    if meta.input.peek(syn::Token![=]) {
+                        attributes.name = Some(meta.value()?.parse::<LitStr>()?.value());
+                    } else {
+                        meta.parse_nested_meta(|nested| {
+                            if nested.path.is_ident("serialize") {
+                                attributes.name = Some(nested.value()?.parse::<LitStr>()?.value());
+                            } else {
+                                nested.value()?.parse::<LitStr>()?;
+                            }
+                            Ok(())
+                        })?;
+                    }
+                } else if meta.path.is_ident("flatten") {
+                    attributes.flatten = true;
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                    // Fields serde never serializes aren't named by the query parameters
+                    attributes.skip = true;
+                } else {
+                    skip_meta(&meta)?;
+                }
+                Ok(())
+            })?;
+        } else if attribute.path().is_ident("sqlx") {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    attributes.output_name = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else {
+                    skip_meta(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+
+    Ok(attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_column_attributes() {
+        let field: Field = parse_quote! {
+            #[serde(rename = "emailAddress", default)]
+            #[paginated(filterable, searchable, column = "u.email")]
+            email: String
+        };
+
+        assert_eq!(
+            parse_column_attributes(&field).unwrap(),
+            ColumnAttributes {
+                name: Some("emailAddress".to_string()),
+                column: Some("u.email".to_string()),
+                filterable: true,
+                searchable: true,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_serde_attributes() {
+        let field: Field = parse_quote! {
+            #[serde(rename(serialize = "emailAddress", deserialize = "email"))]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            email: Option<String>
+        };
+        assert_eq!(
+            parse_column_attributes(&field).unwrap().name.as_deref(),
+            Some("emailAddress")
+        );
+
+        let field: Field = parse_quote! {
+            #[serde(rename = emailAddress)]
+            email: String
+        };
+        assert!(parse_column_attributes(&field).is_err());
+    }
+
+    #[test]
+    fn test_parse_sqlx_attributes() {
+        let field: Field = parse_quote! {
+            #[sqlx(rename = "email_address", default)]
+            #[serde(rename = "emailAddress")]
+            email: String
+        };
+        assert_eq!(
+            parse_column_attributes(&field).unwrap(),
+            ColumnAttributes {
+                name: Some("emailAddress".to_string()),
+                output_name: Some("email_address".to_string()),
+                ..Default::default()
+            }
+        );
+
+        let field: Field = parse_quote! {
+            #[sqlx(try_from = "i64", json(nullable))]
+            id: u64
+        };
+        assert_eq!(parse_column_attributes(&field).unwrap().output_name, None);
+    }
+
+    #[test]
+    fn test_parse_serde_skip() {
+        let field: Field = parse_quote! {
+            #[serde(skip)]
+            password_hash: String
+        };
+        assert!(parse_column_attributes(&field).unwrap().skip);
+
+        let field: Field = parse_quote! {
+            #[serde(default, skip_serializing)]
+            password_hash: String
+        };
+        assert!(parse_column_attributes(&field).unwrap().skip);
+
+        let field: Field = parse_quote! {
+            #[serde(skip_deserializing, skip_serializing_if = "String::is_empty")]
+            email: String
+        };
+        assert!(!parse_column_attributes(&field).unwrap().skip);
+    }
+
+    #[test]
+    fn test_parse_rename_all() {
+        let input: DeriveInput = parse_quote! {
+            #[serde(deny_unknown_fields, rename_all = "camelCase")]
+            struct User;
+        };
+        assert_eq!(
+            parse_rename_all(&input.attrs, "serde").unwrap(),
+            Some("camelCase")
+        );
+
+        let input: DeriveInput = parse_quote! {
+            #[serde(rename_all(serialize = "kebab-case", deserialize = "snake_case"))]
+            struct User;
+        };
+        assert_eq!(
+            parse_rename_all(&input.attrs, "serde").unwrap(),
+            Some("kebab-case")
+        );
+
+        let input: DeriveInput = parse_quote! {
+            #[serde(rename_all(deserialize = "snake_case"))]
+            struct User;
+        };
+        assert_eq!(parse_rename_all(&input.attrs, "serde").unwrap(), None);
+
+        let input: DeriveInput = parse_quote! {
+            #[serde(rename_all = "Title Case")]
+            struct User;
+        };
+        assert!(parse_rename_all(&input.attrs, "serde").is_err());
+
+        let input: DeriveInput = parse_quote! {
+            #[serde(rename_all = "camelCase")]
+            #[sqlx(rename_all = "UPPERCASE")]
+            struct User;
+        };
+        assert_eq!(
+            parse_rename_all(&input.attrs, "sqlx").unwrap(),
+            Some("UPPERCASE")
+        );
+    }
+
+    #[test]
+    fn test_rename_field() {
+        let renamed: Vec<String> = RENAME_RULES
+            .iter()
+            .map(|rule| rename_field("created_at", Some(rule)))
+            .collect();
+
+        assert_eq!(
+            renamed,
+            vec![
+                "created_at",
+                "CREATED_AT",
+                "CreatedAt",
+                "createdAt",
+                "created_at",
+                "CREATED_AT",
+                "created-at",
+                "CREATED-AT",
+            ]
+        );
+        assert_eq!(rename_field("id", Some("PascalCase")), "Id");
+        assert_eq!(rename_field("created_at", None), "created_at");
+    }
+
+    #[test]
+    fn test_parse_sql_type() {
+        let field: Field = parse_quote! {
+            #[paginated(sql_type = "timestamptz")]
+            created_at: String
+        };
+        assert_eq!(
+            parse_column_attributes(&field).unwrap().sql_type.as_deref(),
+            Some("timestamptz")
+        );
+
+        let field: Field = parse_quote! {
+            #[paginated(sql_type = "varchar")]
+            name: String
+        };
+        assert!(parse_column_attributes(&field).is_err());
+    }
+
+    #[test]
+    fn test_parse_search_attributes() {
+        let field: Field = parse_quote! {
+            #[paginated(searchable, search_mode = "starts_with", search_weight = 3)]
+            sku: String
+        };
+        assert_eq!(
+            parse_column_attributes(&field).unwrap(),
+            ColumnAttributes {
+                search_mode: Some("starts_with".to_string()),
+                search_weight: Some(3),
+                searchable: true,
+                ..Default::default()
+            }
+        );
+
+        let field: Field = parse_quote! {
+            #[paginated(search_mode = "fuzzy")]
+            sku: String
+        };
+        assert!(parse_column_attributes(&field).is_err());
+
+        let field: Field = parse_quote! {
+            #[paginated(search_weight = -1)]
+            sku: String
+        };
+        assert!(parse_column_attributes(&field).is_err());
+    }
+
+    #[test]
+    fn test_infer_sql_type() {
+        assert_eq!(infer_sql_type(&parse_quote!(String)), Some("text"));
+        assert_eq!(infer_sql_type(&parse_quote!(Option<i64>)), Some("bigint"));
+        assert_eq!(
+            infer_sql_type(&parse_quote!(chrono::DateTime<chrono::Utc>)),
+            Some("timestamptz")
+        );
+        assert_eq!(
+            infer_sql_type(&parse_quote!(Option<sqlx::types::Uuid>)),
+            Some("uuid")
+        );
+        assert_eq!(infer_sql_type(&parse_quote!(Vec<String>)), None);
+        assert_eq!(infer_sql_type(&parse_quote!(UserStatus)), None);
+    }
+
+    #[test]
+    fn test_parse_unsupported_attribute() {
+        let field: Field = parse_quote! {
+            #[paginated(indexed)]
+            email: String
+        };
+
+        assert!(parse_column_attributes(&field).is_err());
+    }
+
+    #[test]
+    fn test_expand_rejects_tuple_structs() {
+        let input: DeriveInput = parse_quote! {
+            struct UserId(i64);
+        };
+
+        assert!(expand_paginated(&input).is_err());
+    }
+
+    #[test]
+    fn test_field_name_strips_raw_prefix() {
+        let field: Field = parse_quote! {
+            r#type: String
+        };
+
+        assert_eq!(field_name(&field), "type");
+    }
+}
@@ -0,0 +1,9 @@
+use sqlx_paginated::Paginated;
+
+#[derive(Paginated)]
+struct User {
+    #[paginated(sortable, search_mode = "fuzzy")]
+    email: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde::Serialize;
+use sqlx_paginated::Paginated;
+
+#[derive(Serialize, Paginated)]
+struct User {
+    #[serde(rename = email_address)]
+    email: String,
+}
+
+fn main() {}
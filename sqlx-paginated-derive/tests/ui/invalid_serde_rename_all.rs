@@ -0,0 +1,10 @@
+use serde::Serialize;
+use sqlx_paginated::Paginated;
+
+#[derive(Serialize, Paginated)]
+#[serde(rename_all = "Title Case")]
+struct User {
+    email_address: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use sqlx_paginated::Paginated;
+
+#[derive(Paginated)]
+struct User {
+    #[serde(rename = "emailAddress")]
+    email: String,
+}
+
+fn main() {}
@@ -1,13 +1,40 @@
+// Lets the derive macros refer to `::sqlx_paginated` from within this crate
+extern crate self as sqlx_paginated;
+
 mod paginated_query_as;
 
+#[cfg(feature = "postgres")]
+pub use crate::paginated_query_as::paginated_query_as;
+#[cfg(feature = "axum")]
+pub use crate::paginated_query_as::PaginatedQuery;
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub use crate::paginated_query_as::PaginationRejection;
 pub use crate::paginated_query_as::{
-    paginated_query_as, FlatQueryParams, PaginatedQueryBuilder, PaginatedResponse, QueryBuilder,
-    QueryParams, QueryParamsBuilder, QuerySortDirection,
+    paginated_query_as_for, ColumnRegistry, ColumnType, FilterExpr, FlatQueryParams,
+    JsonApiDocument, JsonApiMeta, JsonApiQueryParams, JsonApiResource, ODataQueryParams,
+    PaginatedColumn, PaginatedLinks, PaginatedModel, PaginatedQueryBuilder, PaginatedResponse,
+    PaginationError, PaginationLimits, QueryBuilder, QueryFilterOperator, QueryParams,
+    QueryParamsBuilder, QuerySearchMode, QuerySortDirection, TotalsMode,
 };
+#[cfg(feature = "postgres")]
+pub use crate::paginated_query_as::{FullTextSearch, TrigramSearch};
+#[cfg(feature = "derive")]
+pub use sqlx_paginated_derive::Paginated;
 
 pub mod prelude {
     pub use super::{
-        paginated_query_as, FlatQueryParams, PaginatedQueryBuilder, PaginatedResponse,
-        QueryBuilder, QueryParams, QueryParamsBuilder, QuerySortDirection,
+        paginated_query_as, paginated_query_as_for, ColumnRegistry, ColumnType, FilterExpr,
+        FlatQueryParams, JsonApiDocument, JsonApiMeta, JsonApiQueryParams, JsonApiResource,
+        ODataQueryParams, PaginatedColumn, PaginatedLinks, PaginatedModel, PaginatedQueryBuilder,
+        PaginatedResponse, PaginationError, PaginationLimits, QueryBuilder, QueryFilterOperator,
+        QueryParams, QueryParamsBuilder, QuerySearchMode, QuerySortDirection, TotalsMode,
     };
+    #[cfg(feature = "axum")]
+    pub use crate::paginated_query_as::PaginatedQuery;
+    #[cfg(any(feature = "axum", feature = "actix"))]
+    pub use crate::paginated_query_as::PaginationRejection;
+    #[cfg(feature = "postgres")]
+    pub use crate::paginated_query_as::{FullTextSearch, TrigramSearch};
+    #[cfg(feature = "derive")]
+    pub use sqlx_paginated_derive::Paginated;
 }
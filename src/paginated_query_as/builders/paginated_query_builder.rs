@@ -1,19 +1,64 @@
-use crate::paginated_query_as::examples::postgres_examples::build_query_with_safe_defaults;
-use crate::paginated_query_as::internal::quote_identifier;
+use crate::paginated_query_as::examples::build_query_with_defaults;
+use crate::paginated_query_as::internal::{
+    append_query_string, get_cursor_value, get_search_condition, QueryCursor, QueryCursorDirection,
+    QueryDatabase, QueryDialect, QueryKeysetParams, QueryPaginationParams, QuerySortParams,
+    QuerySortTerm, DEFAULT_SEARCH_WEIGHT, DEFAULT_SORT_COLUMN_NAME, WINDOW_TOTAL_COLUMN_NAME,
+};
 use crate::paginated_query_as::models::QuerySortDirection;
-use crate::{FlatQueryParams, PaginatedResponse, QueryParams};
+#[cfg(feature = "postgres")]
+use crate::paginated_query_as::PostgresSearch;
+use crate::{
+    ColumnRegistry, ColumnType, FlatQueryParams, PaginatedLinks, PaginatedModel, PaginatedResponse,
+    PaginationError, QueryBuilder, QueryParams, TotalsMode,
+};
+#[cfg(feature = "postgres")]
+use crate::{FullTextSearch, TrigramSearch};
+use chrono::{DateTime, Utc};
 use serde::Serialize;
-use sqlx::postgres::PgArguments;
-use sqlx::{postgres::Postgres, query::QueryAs, Execute, FromRow, IntoArguments, Pool};
+#[cfg(feature = "postgres")]
+use sqlx::{postgres::PgRow, Postgres};
+use sqlx::{
+    query::QueryAs, types::Json, Acquire, Arguments, Column, ColumnIndex, Database, Decode, Encode,
+    Execute, Executor, FromRow, IntoArguments, Row, Type,
+};
 
-pub struct PaginatedQueryBuilder<'q, T, A>
+/// Builds the conditions and arguments of a paginated query from its params.
+pub type BuildQueryFn<'q, T, DB> =
+    for<'p> fn(&'p QueryParams<'q, T>) -> (Vec<String>, <DB as Database>::Arguments<'p>);
+
+/// Builds the conditions and arguments of a paginated query from its params, or fails
+/// on invalid params.
+pub type TryBuildQueryFn<'q, T, DB> =
+    for<'p> fn(
+        &'p QueryParams<'q, T>,
+    ) -> Result<(Vec<String>, <DB as Database>::Arguments<'p>), PaginationError>;
+
+/// Custom query builder replacing the default one.
+enum CustomQueryBuilder<'q, T, DB: Database> {
+    Infallible(BuildQueryFn<'q, T, DB>),
+    Fallible(TryBuildQueryFn<'q, T, DB>),
+}
+
+pub struct PaginatedQueryBuilder<'q, T, DB, A>
 where
-    T: for<'r> FromRow<'r, <Postgres as sqlx::Database>::Row> + Send + Unpin,
+    DB: Database,
+    T: for<'r> FromRow<'r, DB::Row> + Send + Unpin,
 {
-    query: QueryAs<'q, Postgres, T, A>,
+    query: QueryAs<'q, DB, T, A>,
     params: QueryParams<'q, T>,
-    totals_count_enabled: bool,
-    build_query_fn: fn(&QueryParams<T>) -> (Vec<String>, PgArguments),
+    sort_requested: bool,
+    totals_mode: TotalsMode,
+    window_totals_count_enabled: bool,
+    keyset_tie_breaker: Option<String>,
+    cursor_signing_key: Option<Vec<u8>>,
+    links_base_url: Option<String>,
+    sort_fallback_column: Option<String>,
+    strict: bool,
+    column_registry: ColumnRegistry,
+    dialect: Box<dyn QueryDialect>,
+    #[cfg(feature = "postgres")]
+    postgres_search: Option<PostgresSearch>,
+    build_query_fn: Option<CustomQueryBuilder<'q, T, DB>>,
 }
 
 /// A builder for constructing and executing paginated queries.
@@ -25,18 +70,20 @@ where
 ///
 /// * `'q`: The lifetime of the query and its arguments
 /// * `T`: The model type that the query will return
+/// * `DB`: The database the query runs on (`Postgres` or `Sqlite`)
 /// * `A`: The type of the query arguments
 ///
 /// # Generic Constraints
 ///
-/// * `T`: Must be deserializable from Postgres rows (`FromRow`), `Send`, and `Unpin`
-/// * `A`: Must be compatible with Postgres arguments and `Send`
-///
-/// (Attention: Only `Pool<Postgres>` is supported at the moment)
-impl<'q, T, A> PaginatedQueryBuilder<'q, T, A>
+/// * `T`: Must be deserializable from the database rows (`FromRow`), `Send`, and `Unpin`
+/// * `A`: Must be compatible with the database arguments and `Send`
+impl<'q, T, DB, A> PaginatedQueryBuilder<'q, T, DB, A>
 where
-    T: for<'r> FromRow<'r, <Postgres as sqlx::Database>::Row> + Send + Unpin + Serialize + Default,
-    A: 'q + IntoArguments<'q, Postgres> + Send,
+    DB: QueryDatabase,
+    T: for<'r> FromRow<'r, DB::Row> + Send + Unpin + Serialize + Default,
+    A: 'q + IntoArguments<'q, DB> + Send,
+    String: for<'a> Encode<'a, DB> + Type<DB>,
+    DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
 {
     /// Creates a new `PaginatedQueryBuilder` with default settings.
     ///
@@ -49,6 +96,9 @@ where
     /// - Totals calculation is enabled
     /// - Uses default query parameters
     /// - Uses safe default query building function
+    /// - Strict mode is disabled
+    /// - Accepts every serialized field of `T::default()` as a column,
+    ///   see `for_model` for models without `Default`
     ///
     /// # Examples
     ///
@@ -61,30 +111,166 @@ where
     /// struct UserExample {
     ///     name: String
     /// }
-    /// let base_query = sqlx::query_as::<_, UserExample>("SELECT * FROM users");
+    /// let base_query = sqlx::query_as::<Postgres, UserExample>("SELECT * FROM users");
     /// let builder = PaginatedQueryBuilder::new(base_query);
     /// ```
-    pub fn new(query: QueryAs<'q, Postgres, T, A>) -> Self {
+    pub fn new(query: QueryAs<'q, DB, T, A>) -> Self {
+        Self::from_column_registry(query, ColumnRegistry::from_struct_fields::<T>())
+    }
+}
+
+impl<'q, T, DB, A> PaginatedQueryBuilder<'q, T, DB, A>
+where
+    DB: QueryDatabase,
+    T: for<'r> FromRow<'r, DB::Row> + Send + Unpin + Serialize + PaginatedModel,
+    A: 'q + IntoArguments<'q, DB> + Send,
+    String: for<'a> Encode<'a, DB> + Type<DB>,
+    DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+{
+    /// Creates a new `PaginatedQueryBuilder` accepting the columns declared by the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The base query to paginate
+    ///
+    /// # Details
+    ///
+    /// Same default settings as `new`, with the columns of `T::column_registry()`,
+    /// so the model doesn't need to implement `Default`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{Paginated, PaginatedQueryBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Paginated)]
+    /// struct UserExample {
+    ///     #[paginated(sortable, searchable)]
+    ///     name: String
+    /// }
+    /// let base_query = sqlx::query_as::<Postgres, UserExample>("SELECT * FROM users");
+    /// let builder = PaginatedQueryBuilder::for_model(base_query);
+    /// # }
+    /// ```
+    pub fn for_model(query: QueryAs<'q, DB, T, A>) -> Self {
+        Self::from_column_registry(query, T::column_registry())
+    }
+}
+
+impl<'q, T, DB, A> PaginatedQueryBuilder<'q, T, DB, A>
+where
+    DB: QueryDatabase,
+    T: for<'r> FromRow<'r, DB::Row> + Send + Unpin + Serialize,
+    A: 'q + IntoArguments<'q, DB> + Send,
+    String: for<'a> Encode<'a, DB> + Type<DB>,
+    DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+{
+    /// Creates a new `PaginatedQueryBuilder` accepting the columns of the given registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The base query to paginate
+    /// * `registry` - Columns accepted for sorting, filtering and searching
+    pub fn from_column_registry(query: QueryAs<'q, DB, T, A>, registry: ColumnRegistry) -> Self {
         Self {
             query,
             params: FlatQueryParams::default().into(),
-            totals_count_enabled: true,
-            build_query_fn: |params| build_query_with_safe_defaults::<T, Postgres>(params),
+            sort_requested: false,
+            totals_mode: TotalsMode::Exact,
+            window_totals_count_enabled: false,
+            keyset_tie_breaker: None,
+            cursor_signing_key: None,
+            links_base_url: None,
+            sort_fallback_column: Some(DEFAULT_SORT_COLUMN_NAME.to_string()),
+            strict: false,
+            column_registry: registry.unqualified(),
+            dialect: DB::dialect(),
+            #[cfg(feature = "postgres")]
+            postgres_search: None,
+            build_query_fn: None,
+        }
+    }
+
+    /// Replaces the default query builder, see `with_fallible_query_builder` for one
+    /// failing on invalid params.
+    pub fn with_query_builder(self, build_query_fn: BuildQueryFn<'q, T, DB>) -> Self {
+        Self {
+            build_query_fn: Some(CustomQueryBuilder::Infallible(build_query_fn)),
+            ..self
         }
     }
 
-    pub fn with_query_builder(
-        self,
-        build_query_fn: fn(&QueryParams<T>) -> (Vec<String>, PgArguments),
-    ) -> Self {
+    /// Replaces the default query builder with one failing on invalid params.
+    ///
+    /// # Arguments
+    ///
+    /// * `build_query_fn` - Builds the conditions and arguments, usually ending with
+    ///   `QueryBuilder::try_build`
+    ///
+    /// # Details
+    ///
+    /// Same as `with_query_builder`, except that the errors of the builder fail
+    /// `fetch_paginated` instead of being left out of the query.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{PaginatedQueryBuilder, QueryBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let base_query = sqlx::query_as::<Postgres, UserExample>("SELECT * FROM users");
+    /// let builder = PaginatedQueryBuilder::new(base_query).with_fallible_query_builder(|params| {
+    ///     QueryBuilder::<UserExample, Postgres>::new()
+    ///         .strict()
+    ///         .with_filters(params)
+    ///         .try_build()
+    /// });
+    /// ```
+    pub fn with_fallible_query_builder(self, build_query_fn: TryBuildQueryFn<'q, T, DB>) -> Self {
         Self {
-            build_query_fn,
+            build_query_fn: Some(CustomQueryBuilder::Fallible(build_query_fn)),
             ..self
         }
     }
 
     pub fn with_params(mut self, params: impl Into<QueryParams<'q, T>>) -> Self {
         self.params = params.into();
+        // Captured up front, as `validate_sort` may replace the default column
+        self.sort_requested = self.params.sort != QuerySortParams::default();
+        self
+    }
+
+    /// Replaces the columns accepted for sorting, filtering and searching.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - Columns of the model, e.g. `T::column_registry()` for a `PaginatedModel`
+    ///
+    /// # Details
+    ///
+    /// By default every serialized field of T is accepted for everything. Conditions and the
+    /// ORDER BY clause apply to the `base_query` CTE wrapping the query, so qualified columns
+    /// are resolved to the output name of their field in its select list, `u.email` of the
+    /// `email` field being sorted and filtered as `email`.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_column_registry(mut self, registry: ColumnRegistry) -> Self {
+        self.column_registry = registry.unqualified();
         self
     }
 
@@ -92,13 +278,271 @@ where
     ///
     /// When disabled, the response will not include total count or total pages.
     /// This can improve query performance for large datasets where the total
-    /// count is not needed.
+    /// count is not needed. Same as `with_totals_mode(TotalsMode::None)`.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn disable_totals_count(self) -> Self {
+        self.with_totals_mode(TotalsMode::None)
+    }
+
+    /// Sets how the total record count is calculated, `TotalsMode::Exact` by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `totals_mode` - Exact count, planner estimate, count capped to a limit, or none
+    ///
+    /// # Details
+    ///
+    /// The response flags estimated and capped totals with `total_exact: false`.
+    /// Databases without a planner estimate (SQLite and MySQL) count exactly in
+    /// `TotalsMode::Estimated`.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{Paginated, PaginatedQueryBuilder, TotalsMode};
+    ///
+    /// #[derive(Serialize, FromRow, Paginated)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let base_query = sqlx::query_as::<Postgres, UserExample>("SELECT * FROM users");
+    /// let builder = PaginatedQueryBuilder::for_model(base_query).with_totals_mode(TotalsMode::ExactUpTo(1000));
+    /// # }
+    /// ```
+    pub fn with_totals_mode(mut self, totals_mode: TotalsMode) -> Self {
+        self.totals_mode = totals_mode;
+        self
+    }
+
+    /// Counts the total records with a `COUNT(*) OVER()` window in the page query,
+    /// instead of a separate `SELECT COUNT(*)` query.
+    ///
+    /// # Details
+    ///
+    /// - The page and its total are fetched in a single round trip, building the
+    ///   conditions once
+    /// - The count is appended as the last column of the rows and ignored when mapping them to T,
+    ///   so T must map its rows by column name, as `#[derive(FromRow)]` does, or by position
+    ///   from the first column, as tuples do. `FromRow` implementations reading every column
+    ///   of the row would see the count and need the separate count query
+    /// - Queries selecting a `__total` column fail with `PaginationError::InvalidConfiguration`
+    /// - Pages past the last record carry no count, the separate count query is then run
+    /// - Applies to `TotalsMode::Exact` only
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{Paginated, PaginatedQueryBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Paginated)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let base_query = sqlx::query_as::<Postgres, UserExample>("SELECT * FROM users");
+    /// let builder = PaginatedQueryBuilder::for_model(base_query).with_window_totals_count();
+    /// # }
+    /// ```
+    pub fn with_window_totals_count(mut self) -> Self {
+        self.window_totals_count_enabled = true;
+        self
+    }
+
+    /// Sets the column used in place of an invalid sort column.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Trusted column to sort by, `created_at` by default
+    ///
+    /// # Details
+    ///
+    /// Sort columns are valid when they are fields of the model and pass the column protection.
+    /// An invalid primary sort column is replaced by the fallback, keeping its direction,
+    /// while invalid secondary sort columns are dropped.
     ///
     /// # Returns
     ///
     /// Returns self for method chaining
-    pub fn disable_totals_count(mut self) -> Self {
-        self.totals_count_enabled = false;
+    pub fn with_sort_fallback(mut self, column: impl Into<String>) -> Self {
+        self.sort_fallback_column = Some(column.into());
+        self
+    }
+
+    /// Rejects invalid sort columns instead of falling back to a default column.
+    ///
+    /// By default an invalid primary sort column is replaced by the sort fallback, `created_at`
+    /// unless set with `with_sort_fallback`, and invalid secondary sort columns are dropped.
+    /// Without the fallback, an unknown sort column makes `fetch_paginated` fail with
+    /// `PaginationError::InvalidSortColumn`, as does a column blocked by the column protection.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn disable_sort_fallback(mut self) -> Self {
+        self.sort_fallback_column = None;
+        self
+    }
+
+    /// Enables strict mode, failing on invalid input instead of silently ignoring it.
+    ///
+    /// # Details
+    ///
+    /// - Invalid sort columns fail with `PaginationError::InvalidSortColumn` instead of falling back
+    /// - Cursors that can't position the page fail with `PaginationError::InvalidCursor`
+    ///   instead of returning the first page
+    /// - The default query builder fails on invalid search, filter and date columns,
+    ///   custom query builders opt in through `QueryBuilder::strict`
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{Paginated, PaginatedQueryBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Paginated)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let base_query = sqlx::query_as::<Postgres, UserExample>("SELECT * FROM users");
+    /// let builder = PaginatedQueryBuilder::for_model(base_query).strict();
+    /// # }
+    /// ```
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Switches from `LIMIT/OFFSET` to keyset (seek) pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `tie_breaker_column` - Unique column used to order rows sharing the same sort value (e.g. `id`)
+    ///
+    /// # Details
+    ///
+    /// - Rows are ordered by the sort column and then by the tie-breaker column
+    /// - When the params carry the last seen row (see `QueryParamsBuilder::with_keyset`),
+    ///   a `(sort_column, tie_breaker) > (value, value)` condition replaces the OFFSET
+    ///   (`<` for descending sorts)
+    /// - The page number is ignored while in keyset mode
+    /// - The response carries `next_cursor` / `prev_cursor` tokens pointing at the
+    ///   neighbouring pages, which can be sent back through the `cursor` parameter
+    /// - The sort and tie-breaker columns must not be NULL, a page bounded by a NULL value
+    ///   fails with `PaginationError::InvalidConfiguration` as no cursor can seek past it
+    /// - Keyset values are cast to the declared type of their column, see
+    ///   `ColumnRegistry::with_column_type`, and bound as text otherwise. PostgreSQL compares
+    ///   text with text columns only, so there `fetch_paginated` fails with
+    ///   `PaginationError::InvalidConfiguration` unless both columns have a declared type
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{Paginated, PaginatedQueryBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Paginated)]
+    /// struct UserExample {
+    ///     id: i64,
+    ///     name: String
+    /// }
+    /// let base_query = sqlx::query_as::<Postgres, UserExample>("SELECT * FROM users");
+    /// let builder = PaginatedQueryBuilder::for_model(base_query).with_keyset_pagination("id");
+    /// # }
+    /// ```
+    pub fn with_keyset_pagination(mut self, tie_breaker_column: impl Into<String>) -> Self {
+        self.keyset_tie_breaker = Some(tie_breaker_column.into());
+        self
+    }
+
+    /// Signs the cursor tokens with an HMAC-SHA256 server key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Secret key used to sign issued cursors and verify received ones
+    ///
+    /// # Details
+    ///
+    /// Once set, unsigned or tampered cursors are ignored and the first page is returned.
+    /// Cursors are always bound to the sort and filters they were issued for,
+    /// signing additionally prevents clients from forging positions.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_cursor_signing_key(mut self, key: impl Into<Vec<u8>>) -> Self {
+        self.cursor_signing_key = Some(key.into());
+        self
+    }
+
+    /// Adds the URLs of the first, previous, next and last pages to the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - URL the query string of each page is appended to, e.g. `/users`
+    ///   or `https://api.example.com/users`
+    ///
+    /// # Details
+    ///
+    /// The query string of each link carries the sort, search, date range and filters of
+    /// the params. Offset pagination links to pages, keyset pagination to the cursors of
+    /// the response. The `last` link needs the total count.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{Paginated, PaginatedQueryBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Paginated)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let base_query = sqlx::query_as::<Postgres, UserExample>("SELECT * FROM users");
+    /// let builder = PaginatedQueryBuilder::for_model(base_query).with_links("/users");
+    /// # }
+    /// ```
+    pub fn with_links(mut self, base_url: impl Into<String>) -> Self {
+        self.links_base_url = Some(base_url.into());
         self
     }
 
@@ -106,114 +550,2660 @@ where
     ///
     /// # Arguments
     ///
-    /// * `pool` - Database connection pool (Attention: Only `Pool<Postgres>` is supported at the moment)
+    /// * `connection` - Where the queries run: a pool (`&Pool`), a transaction
+    ///   (`&mut Transaction`) or a connection (`&mut PgConnection`)
+    ///
+    /// # Details
+    ///
+    /// The count and page queries run on the same connection, acquired once from a pool.
+    /// Within a `REPEATABLE READ` transaction both queries see the same snapshot, and session
+    /// settings such as `SET LOCAL` variables apply to both.
     ///
     /// # Returns
     ///
     /// Returns a Result containing a `PaginatedResponse<T>` with:
     /// - Records for the requested page
     /// - Optional Pagination information (if enabled)
-    /// - Optional total count and total pages (if enabled)
+    /// - Optional total count and total pages, and whether the count is exact (if enabled)
+    /// - Optional next and previous page cursors (if keyset pagination is enabled)
     ///
     /// # Errors
     ///
-    /// Returns `sqlx::Error` if the query execution fails
-    pub async fn fetch_paginated(
-        self,
-        pool: &Pool<Postgres>,
-    ) -> Result<PaginatedResponse<T>, sqlx::Error> {
+    /// Returns `PaginationError::InvalidSortColumn` if the sort column is invalid and the
+    /// sort fallback is disabled, the errors of the query builder, the invalid input rejected
+    /// in strict mode, or `PaginationError::Database` if the query execution fails
+    pub async fn fetch_paginated<'a, C>(
+        mut self,
+        connection: C,
+    ) -> Result<PaginatedResponse<T>, PaginationError>
+    where
+        C: Acquire<'a, Database = DB>,
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+        for<'p> DB::Arguments<'p>: IntoArguments<'p, DB>,
+        i64: for<'r> Decode<'r, DB> + Type<DB>,
+        Json<serde_json::Value>: for<'r> Decode<'r, DB> + Type<DB>,
+        usize: ColumnIndex<DB::Row>,
+    {
+        self.validate_sort()?;
+        self.validate_keyset()?;
+        self.validate_cursor()?;
+
+        let mut connection = connection.acquire().await?;
         let base_sql = self.build_base_query();
-        let (conditions, main_arguments) = (self.build_query_fn)(&self.params);
+        let (conditions, mut main_arguments) = self.build_query()?;
         let where_clause = self.build_where_clause(&conditions);
+        let window_totals =
+            self.totals_mode == TotalsMode::Exact && self.window_totals_count_enabled;
 
-        let (total, total_pages, pagination) = if self.totals_count_enabled {
-            let (_, count_arguments) = (self.build_query_fn)(&self.params);
-            let pagination_arguments = self.params.pagination.clone();
+        let mut total = match window_totals {
+            true => None,
+            false => {
+                self.fetch_total(&mut connection, &base_sql, &where_clause)
+                    .await?
+            }
+        };
 
-            let count_sql = format!(
-                "{} SELECT COUNT(*) FROM base_query{}",
-                base_sql, where_clause
-            );
-            let count: i64 = sqlx::query_scalar_with(&count_sql, count_arguments)
-                .fetch_one(pool)
-                .await?;
+        let keyset_position = self.resolve_keyset_position();
+        let backward = keyset_position
+            .as_ref()
+            .is_some_and(|position| position.direction == QueryCursorDirection::Previous);
+        let keyset_condition = match keyset_position.as_ref() {
+            Some(position) => self.build_keyset_condition(position, &mut main_arguments)?,
+            None => None,
+        };
 
-            let available_pages = match count {
-                0 => 0,
-                _ => (count + pagination_arguments.page_size - 1) / pagination_arguments.page_size,
-            };
+        let mut main_sql = match (window_totals, keyset_condition) {
+            // The keyset condition filters the counted rows, so the rows are counted beforehand
+            (true, Some(keyset_condition)) => format!(
+                "{} SELECT * FROM (SELECT base_query.*, COUNT(*) OVER() AS {} FROM base_query{}) AS counted_query WHERE {}",
+                base_sql, WINDOW_TOTAL_COLUMN_NAME, where_clause, keyset_condition
+            ),
+            (true, None) => format!(
+                "{} SELECT base_query.*, COUNT(*) OVER() AS {} FROM base_query{}",
+                base_sql, WINDOW_TOTAL_COLUMN_NAME, where_clause
+            ),
+            (false, keyset_condition) => {
+                let conditions: Vec<String> =
+                    conditions.into_iter().chain(keyset_condition).collect();
+                format!(
+                    "{} SELECT * FROM base_query{}",
+                    base_sql,
+                    self.build_where_clause(&conditions)
+                )
+            }
+        };
 
-            (
-                Some(count),
-                Some(available_pages),
-                Some(pagination_arguments),
-            )
+        let order_clause = match self.build_relevance_order_clause(&mut main_arguments)? {
+            Some(order_clause) => order_clause,
+            None => self.build_order_clause(backward),
+        };
+
+        main_sql.push_str(&order_clause);
+        main_sql.push_str(&self.build_limit_offset_clause());
+
+        // Rebound after `main_sql` so the arguments are dropped before the SQL they share a lifetime with
+        let main_arguments = main_arguments;
+        let mut records = if window_totals {
+            let rows = sqlx::query_with::<DB, _>(&main_sql, main_arguments)
+                .fetch_all(&mut *connection)
+                .await?;
+
+            // The total is the last column, after the columns of the query mapped to T,
+            // so rows mapped by name or by position from the first column decode without it
+            if let Some(row) = rows.first() {
+                let total_columns = row
+                    .columns()
+                    .iter()
+                    .filter(|column| column.name() == WINDOW_TOTAL_COLUMN_NAME)
+                    .count();
+                if total_columns > 1 {
+                    return Err(PaginationError::InvalidConfiguration(format!(
+                        "the query selects a `{}` column, which is reserved for the window total",
+                        WINDOW_TOTAL_COLUMN_NAME
+                    )));
+                }
+                total = Some((row.try_get::<i64, _>(row.len() - 1)?, true));
+            }
+            rows.iter()
+                .map(T::from_row)
+                .collect::<Result<Vec<T>, sqlx::Error>>()?
         } else {
-            (None, None, None)
+            sqlx::query_as_with::<DB, T, _>(&main_sql, main_arguments)
+                .fetch_all(&mut *connection)
+                .await?
         };
 
-        let mut main_sql = format!("{} SELECT * FROM base_query{}", base_sql, where_clause);
+        // Pages past the last row or the keyset position carry no total
+        if window_totals && total.is_none() {
+            total = match self.is_first_page(keyset_position.as_ref()) {
+                true => Some((0, true)),
+                false => {
+                    self.fetch_total(&mut connection, &base_sql, &where_clause)
+                        .await?
+                }
+            };
+        }
 
-        main_sql.push_str(&self.build_order_clause());
-        main_sql.push_str(&self.build_limit_offset_clause());
+        let has_more = records.len() as i64 > self.params.pagination.page_size;
+        records.truncate(self.params.pagination.page_size as usize);
 
-        let records = sqlx::query_as_with::<Postgres, T, _>(&main_sql, main_arguments)
-            .fetch_all(pool)
-            .await?;
+        if backward {
+            records.reverse();
+        }
 
-        Ok(PaginatedResponse {
+        let (has_next_page, has_previous_page) =
+            self.build_page_links(keyset_position.as_ref(), has_more);
+        let (next_cursor, prev_cursor) =
+            self.build_cursors(&records, keyset_position.as_ref(), has_more)?;
+        let total_exact = total.map(|(_, exact)| exact);
+        let total = total.map(|(count, _)| count);
+        let total_pages = total.map(|count| match count {
+            0 => 0,
+            _ => (count + self.params.pagination.page_size - 1) / self.params.pagination.page_size,
+        });
+        let mut response = PaginatedResponse {
             records,
-            pagination,
+            pagination: Some(self.params.pagination.clone()),
             total,
             total_pages,
-        })
-    }
+            total_exact,
+            has_next_page,
+            has_previous_page,
+            next_cursor,
+            prev_cursor,
+            links: None,
+        };
+        response.links = self.build_links(&response);
 
-    /// Builds the base query with CTE (Common Table Expression).
-    ///
-    /// # Returns
-    ///
-    /// Returns the SQL string for the base query wrapped in a CTE
-    fn build_base_query(&self) -> String {
-        format!("WITH base_query AS ({})", self.query.sql())
+        Ok(response)
     }
 
-    /// Builds the WHERE clause from the provided conditions.
+    /// Calculates the total record count as set by the totals mode.
     ///
     /// # Arguments
     ///
-    /// * `conditions` - Vector of condition strings to join with AND
+    /// * `connection` - Connection the page query runs on
+    /// * `base_sql` - The base query CTE
+    /// * `where_clause` - The WHERE clause of the conditions, without the keyset condition
     ///
     /// # Returns
     ///
-    /// Returns the formatted WHERE clause or empty string if no conditions
-    fn build_where_clause(&self, conditions: &[String]) -> String {
-        if conditions.is_empty() {
-            String::new()
-        } else {
-            format!(" WHERE {}", conditions.join(" AND "))
-        }
-    }
+    /// Returns the count and whether it is exact, or `None` when totals are disabled
+    async fn fetch_total(
+        &self,
+        connection: &mut DB::Connection,
+        base_sql: &str,
+        where_clause: &str,
+    ) -> Result<Option<(i64, bool)>, PaginationError>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+        for<'p> DB::Arguments<'p>: IntoArguments<'p, DB>,
+        i64: for<'r> Decode<'r, DB> + Type<DB>,
+        Json<serde_json::Value>: for<'r> Decode<'r, DB> + Type<DB>,
+        usize: ColumnIndex<DB::Row>,
+    {
+        let count_sql = format!(
+            "{} SELECT COUNT(*) FROM base_query{}",
+            base_sql, where_clause
+        );
 
-    /// Builds the ORDER BY clause based on sort parameters.
+        match self.totals_mode {
+            TotalsMode::None => Ok(None),
+            TotalsMode::Exact => Ok(Some((
+                self.fetch_count(connection, &count_sql).await?,
+                true,
+            ))),
+            TotalsMode::ExactUpTo(limit) => {
+                let limit = limit.max(0);
+                // One row past the limit tells a capped count from an exact one
+                let capped_count_sql = format!(
+                    "{} SELECT COUNT(*) FROM (SELECT 1 FROM base_query{} LIMIT {}) AS capped_query",
+                    base_sql,
+                    where_clause,
+                    limit.saturating_add(1)
+                );
+                let count = self.fetch_count(connection, &capped_count_sql).await?;
+
+                Ok(Some((count.min(limit), count <= limit)))
+            }
+            TotalsMode::Estimated => {
+                let filtered_sql = format!("{} SELECT * FROM base_query{}", base_sql, where_clause);
+                let Some(explain_sql) = self.dialect.explain_query(&filtered_sql) else {
+                    return Ok(Some((
+                        self.fetch_count(connection, &count_sql).await?,
+                        true,
+                    )));
+                };
+
+                let (_, explain_arguments) = self.build_query()?;
+                let plan: Json<serde_json::Value> =
+                    sqlx::query_scalar_with(&explain_sql, explain_arguments)
+                        .fetch_one(&mut *connection)
+                        .await?;
+
+                match self.dialect.row_estimate(&plan) {
+                    Some(estimate) => Ok(Some((estimate, false))),
+                    None => Ok(Some((
+                        self.fetch_count(connection, &count_sql).await?,
+                        true,
+                    ))),
+                }
+            }
+        }
+    }
+
+    /// Runs a count query over the conditions, building the query arguments anew.
+    ///
+    /// # Arguments
+    ///
+    /// * `connection` - Connection the page query runs on
+    /// * `count_sql` - The `SELECT COUNT(*)` query over the conditions
+    async fn fetch_count(
+        &self,
+        connection: &mut DB::Connection,
+        count_sql: &str,
+    ) -> Result<i64, PaginationError>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+        for<'p> DB::Arguments<'p>: IntoArguments<'p, DB>,
+        i64: for<'r> Decode<'r, DB> + Type<DB>,
+        usize: ColumnIndex<DB::Row>,
+    {
+        let (_, count_arguments) = self.build_query()?;
+        let count = sqlx::query_scalar_with(count_sql, count_arguments)
+            .fetch_one(connection)
+            .await?;
+
+        Ok(count)
+    }
+
+    /// Checks whether the page starts at the first row matching the conditions.
+    fn is_first_page(&self, position: Option<&QueryCursor>) -> bool {
+        match self.keyset_tie_breaker {
+            Some(_) => position.is_none(),
+            None => self.params.pagination.page <= 1,
+        }
+    }
+
+    /// Checks the sort columns against the sortable columns and the column protection.
+    ///
+    /// # Details
+    ///
+    /// The default sort column, sorted by when no sort is requested, is replaced by the
+    /// sort fallback or the keyset tie-breaker when the model doesn't have it, whether the
+    /// fallback is disabled or strict mode is enabled or not.
+    ///
+    /// # Returns
+    ///
+    /// Returns `PaginationError::InvalidSortColumn` for the first invalid requested column when
+    /// the sort fallback is disabled or in strict mode, otherwise replaces or drops the invalid
+    /// columns. Returns `PaginationError::InvalidConfiguration` when the invalid primary column
+    /// has to be replaced but neither the fallback nor the tie-breaker is a sortable column
+    fn validate_sort(&mut self) -> Result<(), PaginationError> {
+        let protection = DB::column_protection();
+        let is_sortable =
+            |column: &str| self.column_registry.is_sortable(column) && protection.is_safe(column);
+        let is_default_sort_column = self.params.sort.is_default_sort_column();
+        let mut terms = Vec::new();
+
+        for (index, term) in self.params.sort.get_sort_terms().into_iter().enumerate() {
+            if is_sortable(&term.column) {
+                terms.push(term);
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %term.column, "Invalid sort column");
+
+            let fallback_enabled = self.sort_fallback_column.is_some() && !self.strict;
+            if !is_default_sort_column && !fallback_enabled {
+                return Err(PaginationError::InvalidSortColumn(term.column));
+            }
+            if index > 0 {
+                continue;
+            }
+
+            let fallback = self
+                .sort_fallback_column
+                .iter()
+                .chain(self.keyset_tie_breaker.iter())
+                .find(|column| is_sortable(column))
+                .ok_or_else(|| {
+                    PaginationError::InvalidConfiguration(format!(
+                        "no sortable column to sort by in place of {}, set a sort fallback",
+                        term.column
+                    ))
+                })?;
+            terms.push(QuerySortTerm::new(fallback.clone(), term.direction));
+        }
+
+        self.params.sort = QuerySortParams::from_terms(terms);
+        Ok(())
+    }
+
+    /// Checks that the keyset values can be compared with the sort and tie-breaker columns.
+    ///
+    /// # Returns
+    ///
+    /// Returns `PaginationError::InvalidConfiguration` when either column has no declared type
+    /// on a database comparing text with text columns only, see `keyset_column_type`
+    fn validate_keyset(&self) -> Result<(), PaginationError> {
+        let Some(tie_breaker) = &self.keyset_tie_breaker else {
+            return Ok(());
+        };
+
+        self.keyset_column_type(&self.params.sort.sort_column)?;
+        self.keyset_column_type(tie_breaker)?;
+        Ok(())
+    }
+
+    /// Returns the declared type of a keyset column, `None` binding its values uncast.
+    ///
+    /// # Returns
+    ///
+    /// Returns `PaginationError::InvalidConfiguration` when the column has no declared type on
+    /// PostgreSQL, as values bound uncast are text, which it doesn't compare with other types
+    fn keyset_column_type(&self, column: &str) -> Result<Option<ColumnType>, PaginationError> {
+        match self.column_registry.column_type(column) {
+            None if self.dialect.compares_text_strictly() => {
+                Err(PaginationError::InvalidConfiguration(format!(
+                    "keyset pagination needs the type of the {} column, declare it with ColumnRegistry::with_column_type or #[derive(Paginated)]",
+                    column
+                )))
+            }
+            column_type => Ok(column_type),
+        }
+    }
+
+    /// Rejects a cursor that can't position the page when in strict mode.
+    fn validate_cursor(&self) -> Result<(), PaginationError> {
+        if self.strict && self.params.cursor.is_some() && self.resolve_keyset_position().is_none() {
+            return Err(PaginationError::InvalidCursor);
+        }
+
+        Ok(())
+    }
+
+    /// Builds the conditions and arguments with the custom query builder, if any,
+    /// or with the safe defaults over the column registry (strict in strict mode).
+    fn build_query(&self) -> Result<(Vec<String>, DB::Arguments<'_>), PaginationError> {
+        match &self.build_query_fn {
+            Some(CustomQueryBuilder::Infallible(build_query_fn)) => {
+                return Ok(build_query_fn(&self.params))
+            }
+            Some(CustomQueryBuilder::Fallible(build_query_fn)) => {
+                return build_query_fn(&self.params)
+            }
+            None => {}
+        }
+
+        let query_builder =
+            QueryBuilder::<T, DB>::from_column_registry(self.column_registry.clone());
+        let query_builder = match self.strict {
+            true => query_builder.strict(),
+            false => query_builder,
+        };
+        #[cfg(feature = "postgres")]
+        let query_builder = QueryBuilder {
+            postgres_search: self.postgres_search.clone(),
+            ..query_builder
+        };
+
+        build_query_with_defaults(query_builder, &self.params)
+    }
+
+    /// Renders the quoted SQL column of a field.
+    fn quote_column(&self, column: &str) -> String {
+        self.dialect
+            .quote_identifier(self.column_registry.column_of(column))
+    }
+
+    /// Builds the base query with CTE (Common Table Expression).
+    ///
+    /// # Returns
+    ///
+    /// Returns the SQL string for the base query wrapped in a CTE
+    fn build_base_query(&self) -> String {
+        format!("WITH base_query AS ({})", self.query.sql())
+    }
+
+    /// Builds the WHERE clause from the provided conditions.
+    ///
+    /// # Arguments
+    ///
+    /// * `conditions` - Vector of condition strings to join with AND
+    ///
+    /// # Returns
+    ///
+    /// Returns the formatted WHERE clause or empty string if no conditions
+    fn build_where_clause(&self, conditions: &[String]) -> String {
+        if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", conditions.join(" AND "))
+        }
+    }
+
+    /// Resolves the row the requested page is positioned against in keyset mode.
+    ///
+    /// # Returns
+    ///
+    /// Returns the position decoded from the cursor, which takes precedence, or built from
+    /// the keyset values. Returns `None` for the first page, when keyset pagination is disabled
+    /// or when the cursor is invalid for the current sort and filters.
+    fn resolve_keyset_position(&self) -> Option<QueryCursor> {
+        self.keyset_tie_breaker.as_ref()?;
+
+        if let Some(token) = &self.params.cursor {
+            return match QueryCursor::decode(token, self.cursor_signing_key.as_deref()) {
+                Some(cursor) if cursor.is_valid_for(&self.params) => Some(cursor),
+                _ => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("Ignoring invalid cursor");
+                    None
+                }
+            };
+        }
+
+        Some(QueryCursor::new(
+            &self.params,
+            QueryCursorDirection::Next,
+            self.params.keyset.after_sort_value.as_ref()?,
+            self.params.keyset.after_tie_breaker_value.as_ref()?,
+        ))
+    }
+
+    /// Builds the keyset condition positioning the page next to the given row.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Row the page starts after (or ends before, for previous page cursors)
+    /// * `arguments` - Arguments produced by the query builder, the keyset values are appended to them
+    ///
+    /// # Returns
+    ///
+    /// Returns the row value comparison, or `None` when keyset pagination is disabled.
+    /// Returns `PaginationError::InvalidValue` when a keyset value doesn't parse as the declared
+    /// type of its column, and `PaginationError::BindFailure` when it can't be bound
+    fn build_keyset_condition(
+        &self,
+        position: &QueryCursor,
+        arguments: &mut DB::Arguments<'_>,
+    ) -> Result<Option<String>, PaginationError> {
+        let Some(tie_breaker) = self.keyset_tie_breaker.as_ref() else {
+            return Ok(None);
+        };
+        let sort_column = &self.params.sort.sort_column;
+
+        let comparison = match (&self.params.sort.sort_direction, &position.direction) {
+            (QuerySortDirection::Ascending, QueryCursorDirection::Next)
+            | (QuerySortDirection::Descending, QueryCursorDirection::Previous) => ">",
+            (QuerySortDirection::Descending, QueryCursorDirection::Next)
+            | (QuerySortDirection::Ascending, QueryCursorDirection::Previous) => "<",
+        };
+        let (sort_placeholder, sort_value) =
+            self.keyset_argument(sort_column, &position.sort_value, arguments.len() + 1)?;
+        let (tie_breaker_placeholder, tie_breaker_value) = self.keyset_argument(
+            tie_breaker,
+            &position.tie_breaker_value,
+            arguments.len() + 2,
+        )?;
+
+        let condition = format!(
+            "({}, {}) {} ({}, {})",
+            self.quote_column(sort_column),
+            self.quote_column(tie_breaker),
+            comparison,
+            sort_placeholder,
+            tie_breaker_placeholder
+        );
+        bind_argument::<DB, _>(arguments, sort_value)?;
+        bind_argument::<DB, _>(arguments, tie_breaker_value)?;
+
+        Ok(Some(condition))
+    }
+
+    /// Renders the placeholder of a keyset value and the value to bind to it.
+    ///
+    /// Values of columns with a declared type are normalized and cast to that type. The others
+    /// are bound uncast, as the type guessed from their text may not be the one of the column,
+    /// e.g. `01234` in a text column, which only PostgreSQL rejects, see `keyset_column_type`.
+    fn keyset_argument(
+        &self,
+        column: &str,
+        value: &str,
+        position: usize,
+    ) -> Result<(String, String), PaginationError> {
+        let Some(column_type) = self.keyset_column_type(column)? else {
+            return Ok((self.dialect.placeholder(position), value.to_string()));
+        };
+
+        let parsed =
+            column_type
+                .parse_value(value)
+                .ok_or_else(|| PaginationError::InvalidValue {
+                    column: column.to_string(),
+                    value: value.to_string(),
+                    column_type,
+                })?;
+
+        Ok((
+            self.dialect.typed_column_placeholder(position, column_type),
+            self.dialect.typed_column_value(parsed, column_type),
+        ))
+    }
+
+    /// Builds the ORDER BY clause based on sort parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `reverse` - Inverts the sort direction, used to walk backwards from a previous page cursor
+    ///
+    /// # Returns
+    ///
+    /// Returns the formatted ORDER BY clause with proper column quoting, one term per sort.
+    /// With keyset pagination enabled only the primary sort is used, followed by the tie-breaker column
+    fn build_order_clause(&self, reverse: bool) -> String {
+        format!(" ORDER BY {}", self.build_order_terms(reverse).join(", "))
+    }
+
+    /// Builds the terms of the ORDER BY clause, see `build_order_clause`.
+    fn build_order_terms(&self, reverse: bool) -> Vec<String> {
+        let get_order = |direction: &QuerySortDirection| match (direction, reverse) {
+            (QuerySortDirection::Ascending, false) | (QuerySortDirection::Descending, true) => {
+                "ASC"
+            }
+            (QuerySortDirection::Descending, false) | (QuerySortDirection::Ascending, true) => {
+                "DESC"
+            }
+        };
+
+        match &self.keyset_tie_breaker {
+            Some(tie_breaker) => {
+                let order = get_order(&self.params.sort.sort_direction);
+                vec![
+                    format!(
+                        "{} {}",
+                        self.quote_column(&self.params.sort.sort_column),
+                        order
+                    ),
+                    format!("{} {}", self.quote_column(tie_breaker), order),
+                ]
+            }
+            None => self
+                .params
+                .sort
+                .get_sort_terms()
+                .iter()
+                .map(|term| {
+                    format!(
+                        "{} {}",
+                        self.quote_column(&term.column),
+                        get_order(&term.direction)
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Builds the ORDER BY clause ranking search matches, most relevant first.
+    ///
+    /// # Arguments
+    ///
+    /// * `arguments` - Arguments of the page query, the search values are bound again for the rank
+    ///
+    /// # Details
+    ///
+    /// - Full-text and trigram searches rank by `ts_rank` or `similarity` once rank sorting
+    ///   is enabled
+    /// - Other searches rank by the sum of the search weights of the matching columns, once
+    ///   a searched column has a weight, see `ColumnRegistry::with_search_weight`
     ///
     /// # Returns
     ///
-    /// Returns the formatted ORDER BY clause with proper column quoting
-    fn build_order_clause(&self) -> String {
-        let order = match self.params.sort.sort_direction {
-            QuerySortDirection::Ascending => "ASC",
-            QuerySortDirection::Descending => "DESC",
+    /// Returns the clause ordering by the rank followed by the sort terms breaking ties, or
+    /// `None` unless the search is ranked, the search text isn't empty, no sort is requested
+    /// and keyset pagination is disabled. Returns `PaginationError::BindFailure`
+    /// when a search value can't be bound
+    fn build_relevance_order_clause(
+        &self,
+        arguments: &mut DB::Arguments<'_>,
+    ) -> Result<Option<String>, PaginationError> {
+        let Some(search) = self
+            .params
+            .search
+            .search
+            .as_ref()
+            .filter(|search| !search.trim().is_empty())
+        else {
+            return Ok(None);
         };
-        let column_name = quote_identifier(&self.params.sort.sort_column);
 
-        format!(" ORDER BY {} {}", column_name, order)
+        if self.keyset_tie_breaker.is_some() || self.sort_requested {
+            return Ok(None);
+        }
+
+        let protection = DB::column_protection();
+        let columns: Vec<&String> = self
+            .params
+            .search
+            .search_columns
+            .iter()
+            .flatten()
+            .filter(|column| {
+                self.column_registry.is_searchable(column) && protection.is_safe(column)
+            })
+            .collect();
+
+        #[cfg(feature = "postgres")]
+        if let Some(postgres_search) = &self.postgres_search {
+            if !postgres_search.rank_sort() {
+                return Ok(None);
+            }
+
+            let quoted_columns: Vec<String> = columns
+                .iter()
+                .map(|column| self.quote_column(column))
+                .collect();
+            let placeholder = self.dialect.placeholder(arguments.len() + 1);
+            let Some(rank) =
+                postgres_search.rank(self.dialect.as_ref(), &quoted_columns, &placeholder)
+            else {
+                return Ok(None);
+            };
+            bind_argument::<DB, _>(arguments, search.clone())?;
+
+            return Ok(Some(self.build_ranked_order_clause(&rank)));
+        }
+
+        if !columns
+            .iter()
+            .any(|column| self.column_registry.search_weight(column).is_some())
+        {
+            return Ok(None);
+        }
+
+        let mut scores = Vec::new();
+        for column in columns {
+            let Some((condition, value)) = get_search_condition(
+                self.dialect.as_ref(),
+                &self.quote_column(column),
+                self.column_registry.column_type(column),
+                self.column_registry
+                    .search_mode(column)
+                    .unwrap_or(self.params.search.search_mode),
+                search,
+                arguments.len() + 1,
+            ) else {
+                continue;
+            };
+            let weight = self
+                .column_registry
+                .search_weight(column)
+                .unwrap_or(DEFAULT_SEARCH_WEIGHT);
+            bind_argument::<DB, _>(arguments, value)?;
+            scores.push(format!(
+                "CASE WHEN {} THEN {} ELSE 0 END",
+                condition, weight
+            ));
+        }
+
+        if scores.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(self.build_ranked_order_clause(&format!(
+            "({})",
+            scores.join(" + ")
+        ))))
+    }
+
+    /// Builds the ORDER BY clause by a rank, see `build_relevance_order_clause`.
+    fn build_ranked_order_clause(&self, rank: &str) -> String {
+        let terms: Vec<String> = std::iter::once(format!("{} DESC", rank))
+            .chain(self.build_order_terms(false))
+            .collect();
+
+        format!(" ORDER BY {}", terms.join(", "))
     }
 
+    /// Builds the LIMIT clause, and the OFFSET clause unless in keyset mode.
+    ///
+    /// # Returns
+    ///
+    /// Returns the clause limiting the rows to one past the page size, the extra row
+    /// telling whether there is a next page
     fn build_limit_offset_clause(&self) -> String {
         let pagination = &self.params.pagination;
+        let limit = pagination.page_size + 1;
+
+        if self.keyset_tie_breaker.is_some() {
+            return format!(" LIMIT {}", limit);
+        }
+
         let offset = (pagination.page - 1) * pagination.page_size;
 
-        format!(" LIMIT {} OFFSET {}", pagination.page_size, offset)
+        format!(" LIMIT {} OFFSET {}", limit, offset)
+    }
+
+    /// Tells whether there are pages after and before the current page.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Position the current page was fetched from, if any
+    /// * `has_more` - Whether a row was fetched past the page size, in fetch order
+    ///
+    /// # Returns
+    ///
+    /// Returns `(has_next_page, has_previous_page)`
+    fn build_page_links(&self, position: Option<&QueryCursor>, has_more: bool) -> (bool, bool) {
+        match position {
+            // Previous pages are fetched backwards, so the extra row lies before the page
+            Some(position) if position.direction == QueryCursorDirection::Previous => {
+                (true, has_more)
+            }
+            Some(_) => (has_more, true),
+            None if self.keyset_tie_breaker.is_some() => (has_more, false),
+            None => (has_more, self.params.pagination.page > 1),
+        }
+    }
+
+    /// Builds the cursors pointing at the pages around the fetched records.
+    ///
+    /// # Arguments
+    ///
+    /// * `records` - Records of the current page, in sort order
+    /// * `position` - Position the current page was fetched from, if any
+    /// * `has_more` - Whether a row was fetched past the page size, in fetch order
+    ///
+    /// # Returns
+    ///
+    /// Returns the next and previous page cursors, `None` when there is no such page
+    /// or keyset pagination is disabled.
+    /// Returns `PaginationError::InvalidConfiguration` when a record bounding the page can't
+    /// position a cursor, see `build_cursor`
+    fn build_cursors(
+        &self,
+        records: &[T],
+        position: Option<&QueryCursor>,
+        has_more: bool,
+    ) -> Result<(Option<String>, Option<String>), PaginationError> {
+        let (has_next_page, has_previous_page) = self.build_page_links(position, has_more);
+
+        let next_cursor = match records.last().filter(|_| has_next_page) {
+            Some(record) => self.build_cursor(record, QueryCursorDirection::Next)?,
+            None => None,
+        };
+        let prev_cursor = match records.first().filter(|_| has_previous_page) {
+            Some(record) => self.build_cursor(record, QueryCursorDirection::Previous)?,
+            None => None,
+        };
+
+        Ok((next_cursor, prev_cursor))
+    }
+
+    /// Builds the URLs of the pages around the current one.
+    ///
+    /// # Returns
+    ///
+    /// Returns the links, or `None` when they are disabled
+    fn build_links(&self, response: &PaginatedResponse<T>) -> Option<PaginatedLinks> {
+        let base_url = self.links_base_url.as_ref()?;
+        let link = |page: i64, cursor: Option<&str>| {
+            let params = QueryParams::<T> {
+                pagination: QueryPaginationParams {
+                    page,
+                    page_size: self.params.pagination.page_size,
+                },
+                keyset: QueryKeysetParams::default(),
+                cursor: cursor.map(str::to_string),
+                ..self.params.clone()
+            };
+
+            append_query_string(base_url, &params.to_query_string())
+        };
+
+        Some(PaginatedLinks::from_response(
+            response,
+            self.keyset_tie_breaker.is_some(),
+            link,
+        ))
+    }
+
+    /// Builds the cursor token for a record, reading its sort and tie-breaker values.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` when keyset pagination is disabled. Returns
+    /// `PaginationError::InvalidConfiguration` when the sort or tie-breaker value of the record
+    /// is NULL or missing from its serialized fields, as the keyset condition can't seek past it
+    fn build_cursor(
+        &self,
+        record: &T,
+        direction: QueryCursorDirection,
+    ) -> Result<Option<String>, PaginationError> {
+        let Some(tie_breaker) = self.keyset_tie_breaker.as_ref() else {
+            return Ok(None);
+        };
+        let record = serde_json::to_value(record).unwrap_or_default();
+        let cursor_value = |column: &str| {
+            record.get(column).and_then(get_cursor_value).ok_or_else(|| {
+                PaginationError::InvalidConfiguration(format!(
+                    "the {} value bounding the page can't position a cursor, keyset pagination needs non-null sort and tie-breaker columns",
+                    column
+                ))
+            })
+        };
+        let sort_value = cursor_value(&self.params.sort.sort_column)?;
+        let tie_breaker_value = cursor_value(tie_breaker)?;
+
+        let cursor = QueryCursor::new(&self.params, direction, sort_value, tie_breaker_value);
+
+        Ok(Some(cursor.encode(self.cursor_signing_key.as_deref())))
+    }
+}
+
+/// Binds a value to the arguments of a query.
+///
+/// # Returns
+///
+/// Returns `PaginationError::BindFailure` when the value can't be encoded, leaving the
+/// arguments as they were
+fn bind_argument<'q, DB, V>(
+    arguments: &mut DB::Arguments<'q>,
+    value: V,
+) -> Result<(), PaginationError>
+where
+    DB: Database,
+    V: 'q + Encode<'q, DB> + Type<DB>,
+{
+    arguments.add(value).map_err(PaginationError::BindFailure)
+}
+
+#[cfg(feature = "postgres")]
+impl<'q, T, A> PaginatedQueryBuilder<'q, T, Postgres, A>
+where
+    T: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+{
+    /// Searches with PostgreSQL full-text search instead of LIKE conditions.
+    ///
+    /// # Arguments
+    ///
+    /// * `full_text_search` - The text search configuration, the optional `tsvector` column
+    ///   and whether to order by rank
+    ///
+    /// # Details
+    ///
+    /// Applies to the default query builder, a custom query builder sets it on its own
+    /// `QueryBuilder`. With rank sorting, pages without an explicit sort are ordered by
+    /// `ts_rank` first, except with keyset pagination as the rank can't position a cursor.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{FullTextSearch, Paginated, PaginatedQueryBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Paginated)]
+    /// struct ArticleExample {
+    ///     title: String
+    /// }
+    ///
+    /// let base_query = sqlx::query_as::<Postgres, ArticleExample>("SELECT * FROM articles");
+    /// let builder = PaginatedQueryBuilder::for_model(base_query).with_full_text_search(
+    ///     FullTextSearch::new("english")
+    ///         .with_vector_column("search_vector")
+    ///         .with_rank_sort(),
+    /// );
+    /// # }
+    /// ```
+    pub fn with_full_text_search(mut self, full_text_search: FullTextSearch) -> Self {
+        self.postgres_search = Some(PostgresSearch::FullText(full_text_search));
+        self
+    }
+
+    /// Searches with `pg_trgm` trigram similarity instead of LIKE conditions.
+    ///
+    /// # Arguments
+    ///
+    /// * `trigram_search` - The optional similarity threshold and whether to order by similarity
+    ///
+    /// # Details
+    ///
+    /// Applies to the default query builder and replaces a full-text search. With rank
+    /// sorting, pages without an explicit sort are ordered by the similarity of the most
+    /// similar search column first, except with keyset pagination.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{Paginated, PaginatedQueryBuilder, TrigramSearch};
+    ///
+    /// #[derive(Serialize, FromRow, Paginated)]
+    /// struct CustomerExample {
+    ///     name: String
+    /// }
+    ///
+    /// let base_query = sqlx::query_as::<Postgres, CustomerExample>("SELECT * FROM customers");
+    /// let builder = PaginatedQueryBuilder::for_model(base_query)
+    ///     .with_trigram_search(TrigramSearch::new().with_threshold(0.4).with_rank_sort());
+    /// # }
+    /// ```
+    pub fn with_trigram_search(mut self, trigram_search: TrigramSearch) -> Self {
+        self.postgres_search = Some(PostgresSearch::Trigram(trigram_search));
+        self
+    }
+}
+
+#[cfg(all(test, feature = "postgres"))]
+mod tests {
+    use super::*;
+    use crate::{ColumnType, PaginatedColumn, QueryParamsBuilder, QuerySearchMode};
+    use sqlx::postgres::PgArguments;
+    use sqlx::Postgres;
+
+    #[derive(Debug, Default, Serialize, FromRow)]
+    struct TestModel {
+        id: i64,
+        name: String,
+        created_at: String,
+    }
+
+    fn test_builder(
+        params: QueryParams<'static, TestModel>,
+    ) -> PaginatedQueryBuilder<'static, TestModel, Postgres, PgArguments> {
+        PaginatedQueryBuilder::new(sqlx::query_as::<Postgres, TestModel>("SELECT * FROM users"))
+            .with_params(params)
+    }
+
+    /// Builder in keyset mode over `id`, with the column types PostgreSQL compares keyset values by.
+    fn test_keyset_builder(
+        params: QueryParams<'static, TestModel>,
+    ) -> PaginatedQueryBuilder<'static, TestModel, Postgres, PgArguments> {
+        test_builder(params)
+            .with_column_registry(
+                ColumnRegistry::from_struct_fields::<TestModel>()
+                    .with_column_type("id", ColumnType::BigInt)
+                    .with_column_type("name", ColumnType::Text)
+                    .with_column_type("created_at", ColumnType::TimestampTz),
+            )
+            .with_keyset_pagination("id")
+    }
+
+    #[test]
+    fn test_offset_pagination_clauses() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(3, 20)
+            .build();
+        let builder = test_builder(params);
+
+        assert_eq!(
+            builder.build_order_clause(false),
+            " ORDER BY \"created_at\" DESC"
+        );
+        assert_eq!(builder.build_limit_offset_clause(), " LIMIT 21 OFFSET 40");
+    }
+
+    #[test]
+    fn test_multi_column_order_clause() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sorts(vec![
+                ("created_at", QuerySortDirection::Descending),
+                ("name", QuerySortDirection::Ascending),
+                ("id", QuerySortDirection::Ascending),
+            ])
+            .build();
+        let builder = test_builder(params);
+
+        assert_eq!(
+            builder.build_order_clause(false),
+            " ORDER BY \"created_at\" DESC, \"name\" ASC, \"id\" ASC"
+        );
+
+        // Keyset pagination seeks on the primary sort and the tie-breaker only
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sorts(vec![
+                ("created_at", QuerySortDirection::Descending),
+                ("name", QuerySortDirection::Ascending),
+            ])
+            .build();
+        let builder = test_keyset_builder(params);
+
+        assert_eq!(
+            builder.build_order_clause(false),
+            " ORDER BY \"created_at\" DESC, \"id\" DESC"
+        );
+    }
+
+    #[test]
+    fn test_full_text_search_rank_order_clause() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_search("rust", vec!["name"])
+            .with_filter("id", Some("3"))
+            .build();
+        let builder = test_builder(params)
+            .with_full_text_search(FullTextSearch::new("english").with_rank_sort());
+        let (conditions, mut arguments) = builder.build_query().unwrap();
+
+        assert_eq!(
+            conditions[0],
+            "to_tsvector('english', coalesce(\"name\"::text, '')) @@ websearch_to_tsquery('english', $1)"
+        );
+        assert_eq!(
+            builder.build_relevance_order_clause(&mut arguments).unwrap().unwrap(),
+            " ORDER BY ts_rank(to_tsvector('english', coalesce(\"name\"::text, '')), websearch_to_tsquery('english', $3)) DESC, \"created_at\" DESC"
+        );
+        assert_eq!(arguments.len(), 3);
+
+        // An explicit sort, keyset pagination or no rank sorting keep the sort terms
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_search("rust", vec!["name"])
+            .with_sort("name", QuerySortDirection::Ascending)
+            .build();
+        let builder = test_builder(params)
+            .with_full_text_search(FullTextSearch::new("english").with_rank_sort());
+        assert!(builder
+            .build_relevance_order_clause(&mut PgArguments::default())
+            .unwrap()
+            .is_none());
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_search("rust", vec!["name"])
+            .build();
+        let builder = test_builder(params.clone())
+            .with_full_text_search(FullTextSearch::new("english").with_rank_sort())
+            .with_keyset_pagination("id");
+        assert!(builder
+            .build_relevance_order_clause(&mut PgArguments::default())
+            .unwrap()
+            .is_none());
+
+        let builder = test_builder(params).with_full_text_search(FullTextSearch::new("english"));
+        assert!(builder
+            .build_relevance_order_clause(&mut PgArguments::default())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_trigram_search_rank_order_clause() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_search("jhon", vec!["name", "id"])
+            .build();
+        let builder =
+            test_builder(params).with_trigram_search(TrigramSearch::new().with_rank_sort());
+        let (conditions, mut arguments) = builder.build_query().unwrap();
+
+        assert_eq!(
+            conditions,
+            vec!["(\"name\"::text % $1 OR \"id\"::text % $1)"]
+        );
+        assert_eq!(
+            builder.build_relevance_order_clause(&mut arguments).unwrap().unwrap(),
+            " ORDER BY GREATEST(similarity(\"name\"::text, $2), similarity(\"id\"::text, $2)) DESC, \"created_at\" DESC"
+        );
+    }
+
+    #[test]
+    fn test_weighted_search_relevance_order_clause() {
+        let registry = ColumnRegistry::from_struct_fields::<TestModel>()
+            .with_column_type("id", ColumnType::BigInt)
+            .with_search_mode("id", QuerySearchMode::Exact)
+            .with_search_weight("id", 5);
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_search("7", vec!["id", "name"])
+            .build();
+        let builder = test_builder(params).with_column_registry(registry.clone());
+        let (conditions, mut arguments) = builder.build_query().unwrap();
+
+        assert_eq!(
+            conditions,
+            vec!["(\"id\" = $1::bigint OR LOWER(\"name\") LIKE LOWER($2) ESCAPE '!')"]
+        );
+        assert_eq!(
+            builder
+                .build_relevance_order_clause(&mut arguments)
+                .unwrap()
+                .unwrap(),
+            " ORDER BY (CASE WHEN \"id\" = $3::bigint THEN 5 ELSE 0 END \
+             + CASE WHEN LOWER(\"name\") LIKE LOWER($4) ESCAPE '!' THEN 1 ELSE 0 END) DESC, \
+             \"created_at\" DESC"
+        );
+        assert_eq!(arguments.len(), 4);
+
+        // Search text that isn't a value of the type skips the exact search of the column
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_search("rust", vec!["id", "name"])
+            .build();
+        let builder = test_builder(params).with_column_registry(registry.clone());
+        assert_eq!(
+            builder.build_query().unwrap().0,
+            vec!["(LOWER(\"name\") LIKE LOWER($1) ESCAPE '!')"]
+        );
+
+        // Searches without weighted columns keep the sort terms
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_search("7", vec!["name"])
+            .build();
+        let builder = test_builder(params).with_column_registry(registry);
+        assert!(builder
+            .build_relevance_order_clause(&mut PgArguments::default())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_relevance_order_clause_without_default_sort_column() {
+        #[derive(Debug, Default, Serialize, FromRow)]
+        struct DocumentModel {
+            id: i64,
+            title: String,
+        }
+
+        // The fallback replacing the missing default column isn't a requested sort
+        let params = QueryParamsBuilder::<DocumentModel>::new()
+            .with_search("rust", vec!["title"])
+            .build();
+        let mut builder =
+            PaginatedQueryBuilder::<_, Postgres, _>::new(
+                sqlx::query_as::<Postgres, DocumentModel>("SELECT * FROM documents"),
+            )
+            .with_params(params)
+            .with_sort_fallback("id")
+            .with_full_text_search(FullTextSearch::new("english").with_rank_sort());
+        builder.validate_sort().unwrap();
+
+        assert_eq!(builder.params.sort.sort_column, "id");
+        assert_eq!(
+            builder
+                .build_relevance_order_clause(&mut PgArguments::default())
+                .unwrap()
+                .unwrap(),
+            " ORDER BY ts_rank(to_tsvector('english', coalesce(\"title\"::text, '')), websearch_to_tsquery('english', $1)) DESC, \"id\" DESC"
+        );
+
+        let params = QueryParamsBuilder::<DocumentModel>::new()
+            .with_search("rust", vec!["title"])
+            .build();
+        let mut builder =
+            PaginatedQueryBuilder::<_, Postgres, _>::new(
+                sqlx::query_as::<Postgres, DocumentModel>("SELECT * FROM documents"),
+            )
+            .with_params(params)
+            .with_sort_fallback("id")
+            .with_column_registry(
+                ColumnRegistry::from_struct_fields::<DocumentModel>()
+                    .with_search_weight("title", 2),
+            );
+        builder.validate_sort().unwrap();
+
+        assert_eq!(
+            builder
+                .build_relevance_order_clause(&mut PgArguments::default())
+                .unwrap()
+                .unwrap(),
+            " ORDER BY (CASE WHEN LOWER(\"title\") LIKE LOWER($1) ESCAPE '!' THEN 2 ELSE 0 END) DESC, \"id\" DESC"
+        );
+    }
+
+    #[test]
+    fn test_multi_column_sort_from_flat_params() {
+        let flat_params: FlatQueryParams = serde_json::from_value(serde_json::json!({
+            "sort": "-name,id",
+            "sort_column": "created_at",
+        }))
+        .unwrap();
+        let builder = test_builder(flat_params.into());
+
+        assert!(builder.params.filters.is_empty());
+        assert_eq!(builder.params.sort.sort_column, "name");
+        assert_eq!(
+            builder.params.sort.sort_direction,
+            QuerySortDirection::Descending
+        );
+        assert_eq!(
+            builder.build_order_clause(false),
+            " ORDER BY \"name\" DESC, \"id\" ASC"
+        );
+    }
+
+    #[test]
+    fn test_invalid_sort_columns_fall_back() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sorts(vec![
+                ("password", QuerySortDirection::Ascending),
+                ("name", QuerySortDirection::Descending),
+                ("pg_catalog.pg_class", QuerySortDirection::Ascending),
+            ])
+            .build();
+        let mut builder = test_builder(params).with_sort_fallback("id");
+
+        builder.validate_sort().unwrap();
+        assert_eq!(
+            builder.build_order_clause(false),
+            " ORDER BY \"id\" ASC, \"name\" DESC"
+        );
+    }
+
+    #[test]
+    fn test_sort_columns_from_registry() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sorts(vec![
+                ("name", QuerySortDirection::Ascending),
+                ("id", QuerySortDirection::Descending),
+            ])
+            .build();
+        let registry = ColumnRegistry::new(vec![
+            PaginatedColumn {
+                column: "full_name".to_string(),
+                ..PaginatedColumn::new("name")
+            },
+            PaginatedColumn {
+                sortable: false,
+                ..PaginatedColumn::new("id")
+            },
+        ]);
+        let mut builder = test_builder(params).with_column_registry(registry);
+
+        builder.validate_sort().unwrap();
+        assert_eq!(
+            builder.build_order_clause(false),
+            " ORDER BY \"full_name\" ASC"
+        );
+    }
+
+    #[test]
+    fn test_paginated_query_as_entry_points() {
+        let builder = crate::paginated_query_as::<TestModel>("SELECT * FROM users");
+        assert_eq!(
+            builder.build_base_query(),
+            "WITH base_query AS (SELECT * FROM users)"
+        );
+
+        let builder: PaginatedQueryBuilder<TestModel, Postgres, _> =
+            crate::paginated_query_as!(TestModel, "SELECT * FROM users");
+        assert_eq!(
+            builder.build_base_query(),
+            "WITH base_query AS (SELECT * FROM users)"
+        );
+    }
+
+    #[test]
+    fn test_builder_for_model_without_default() {
+        #[derive(Debug, Serialize, FromRow)]
+        struct AccountModel {
+            id: i64,
+            email: String,
+        }
+
+        impl PaginatedModel for AccountModel {
+            fn column_registry() -> ColumnRegistry {
+                ColumnRegistry::new(vec![
+                    PaginatedColumn::new("id"),
+                    PaginatedColumn {
+                        column: "a.email".to_string(),
+                        sortable: false,
+                        ..PaginatedColumn::new("email")
+                    },
+                ])
+            }
+        }
+
+        let params = QueryParamsBuilder::<AccountModel>::for_model()
+            .with_sort("email", QuerySortDirection::Ascending)
+            .with_filter("email", Some("john@example.com"))
+            .build();
+        let mut builder = PaginatedQueryBuilder::for_model(
+            sqlx::query_as::<Postgres, AccountModel>("SELECT * FROM accounts a"),
+        )
+        .with_params(params)
+        .with_sort_fallback("id");
+        let (conditions, _) = builder.build_query().unwrap();
+
+        builder.validate_sort().unwrap();
+        // Conditions apply to the select list of the base_query CTE, where `a` isn't in scope
+        assert_eq!(conditions, vec!["\"email\" = $1"]);
+        assert_eq!(builder.build_order_clause(false), " ORDER BY \"id\" ASC");
+    }
+
+    #[test]
+    fn test_invalid_sort_column_rejected_without_fallback() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sorts(vec![
+                ("name", QuerySortDirection::Ascending),
+                ("password", QuerySortDirection::Descending),
+            ])
+            .build();
+        let mut builder = test_builder(params).disable_sort_fallback();
+
+        assert!(matches!(
+            builder.validate_sort(),
+            Err(PaginationError::InvalidSortColumn(column)) if column == "password"
+        ));
+    }
+
+    #[test]
+    fn test_missing_default_sort_column_falls_back() {
+        #[derive(Debug, Default, Serialize, FromRow)]
+        struct ItemModel {
+            id: i64,
+            name: String,
+        }
+        let item_builder = || {
+            PaginatedQueryBuilder::new(sqlx::query_as::<Postgres, ItemModel>("SELECT * FROM items"))
+        };
+
+        // No sort requested isn't an invalid sort, even in strict mode
+        let mut builder = item_builder().with_sort_fallback("id").strict();
+        builder.validate_sort().unwrap();
+        assert_eq!(builder.build_order_clause(false), " ORDER BY \"id\" DESC");
+
+        let mut builder = item_builder()
+            .disable_sort_fallback()
+            .with_keyset_pagination("id");
+        builder.validate_sort().unwrap();
+        assert_eq!(builder.params.sort.sort_column, "id");
+
+        // Without a sortable fallback the configuration is at fault
+        let mut builder = item_builder().strict();
+        assert!(matches!(
+            builder.validate_sort(),
+            Err(PaginationError::InvalidConfiguration(_))
+        ));
+
+        let params = QueryParamsBuilder::<ItemModel>::new()
+            .with_sort("created_at", QuerySortDirection::Ascending)
+            .build();
+        let mut builder = item_builder().with_params(params);
+        assert!(matches!(
+            builder.validate_sort(),
+            Err(PaginationError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn test_keyset_pagination_clauses() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(3, 20)
+            .with_sort("created_at", QuerySortDirection::Ascending)
+            .with_keyset("2024-11-02T12:30:12Z", "42")
+            .build();
+        let builder = test_keyset_builder(params);
+        let position = builder.resolve_keyset_position().unwrap();
+        let mut arguments = PgArguments::default();
+        arguments.add("active".to_string()).unwrap();
+
+        assert_eq!(
+            builder
+                .build_keyset_condition(&position, &mut arguments)
+                .unwrap(),
+            Some(
+                "(\"created_at\", \"id\") > ($2::timestamp with time zone, $3::bigint)".to_string()
+            )
+        );
+        assert_eq!(arguments.len(), 3);
+        assert_eq!(
+            builder.build_order_clause(false),
+            " ORDER BY \"created_at\" ASC, \"id\" ASC"
+        );
+        assert_eq!(builder.build_limit_offset_clause(), " LIMIT 21");
+    }
+
+    #[test]
+    fn test_keyset_values_cast_to_declared_types() {
+        let keyset_condition = |sort_column: &str, sort_value: &str| {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_sort(sort_column, QuerySortDirection::Ascending)
+                .with_keyset(sort_value, "42")
+                .build();
+            let builder = test_keyset_builder(params);
+            let position = builder.resolve_keyset_position().unwrap();
+
+            builder.build_keyset_condition(&position, &mut PgArguments::default())
+        };
+
+        // Numeric looking values of text columns aren't cast to a number
+        assert_eq!(
+            keyset_condition("name", "01234").unwrap(),
+            Some("(\"name\", \"id\") > ($1::text, $2::bigint)".to_string())
+        );
+        assert!(matches!(
+            keyset_condition("created_at", "yesterday"),
+            Err(PaginationError::InvalidValue { column, .. }) if column == "created_at"
+        ));
+    }
+
+    #[test]
+    fn test_keyset_columns_without_type_rejected() {
+        // Values bound uncast are text, which PostgreSQL doesn't compare with a timestamptz
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_keyset("2024-11-02T12:30:12Z", "42")
+            .build();
+        let builder = test_builder(params).with_keyset_pagination("id");
+        let position = builder.resolve_keyset_position().unwrap();
+
+        assert!(matches!(
+            builder.validate_keyset(),
+            Err(PaginationError::InvalidConfiguration(message)) if message.contains("created_at")
+        ));
+        assert!(matches!(
+            builder.build_keyset_condition(&position, &mut PgArguments::default()),
+            Err(PaginationError::InvalidConfiguration(_))
+        ));
+        assert!(
+            test_keyset_builder(QueryParamsBuilder::<TestModel>::new().build())
+                .validate_keyset()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_bind_failure_reported() {
+        struct UnencodableValue;
+
+        impl Type<Postgres> for UnencodableValue {
+            fn type_info() -> sqlx::postgres::PgTypeInfo {
+                <String as Type<Postgres>>::type_info()
+            }
+        }
+
+        impl Encode<'_, Postgres> for UnencodableValue {
+            fn encode_by_ref(
+                &self,
+                _buffer: &mut sqlx::postgres::PgArgumentBuffer,
+            ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                Err("unencodable value".into())
+            }
+        }
+
+        // Bind failures fail the page instead of dropping the condition they belong to
+        let mut arguments = PgArguments::default();
+        bind_argument::<Postgres, _>(&mut arguments, "2024-11-02T12:30:12Z".to_string()).unwrap();
+        let error = bind_argument::<Postgres, _>(&mut arguments, UnencodableValue).unwrap_err();
+
+        assert!(matches!(error, PaginationError::BindFailure(_)));
+        assert!(!error.is_client_error());
+        assert_eq!(arguments.len(), 1);
+    }
+
+    #[test]
+    fn test_keyset_pagination_descending() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_keyset("2024-11-02T12:30:12Z", "42")
+            .build();
+        let builder = test_keyset_builder(params);
+        let position = builder.resolve_keyset_position().unwrap();
+        let mut arguments = PgArguments::default();
+
+        let condition = builder
+            .build_keyset_condition(&position, &mut arguments)
+            .unwrap()
+            .unwrap();
+
+        assert!(condition.contains(") < ($1"));
+    }
+
+    #[test]
+    fn test_keyset_pagination_first_page() {
+        let params = QueryParamsBuilder::<TestModel>::new().build();
+        let builder = test_keyset_builder(params);
+
+        assert!(builder.resolve_keyset_position().is_none());
+    }
+
+    fn test_records() -> Vec<TestModel> {
+        (1..=10)
+            .map(|id| TestModel {
+                id,
+                name: format!("user {}", id),
+                created_at: format!("2024-11-{:02}T12:30:12Z", 20 - id),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cursor_pagination_round_trip() {
+        let params = QueryParamsBuilder::<TestModel>::new().build();
+        let builder = test_builder(params)
+            .with_keyset_pagination("id")
+            .with_cursor_signing_key("secret");
+        let records = test_records();
+
+        let (next_cursor, prev_cursor) = builder.build_cursors(&records, None, true).unwrap();
+        assert!(prev_cursor.is_none(), "First page has no previous page");
+
+        let next_params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(7, 10)
+            .with_cursor(next_cursor.unwrap())
+            .build();
+        let next_builder = test_keyset_builder(next_params).with_cursor_signing_key("secret");
+        let position = next_builder.resolve_keyset_position().unwrap();
+
+        assert_eq!(position.direction, QueryCursorDirection::Next);
+        assert_eq!(position.sort_value, "2024-11-10T12:30:12Z");
+        assert_eq!(position.tie_breaker_value, "10");
+        assert_eq!(next_builder.build_limit_offset_clause(), " LIMIT 11");
+
+        let (_, prev_cursor) = next_builder
+            .build_cursors(&records[..3], Some(&position), false)
+            .unwrap();
+        let prev_params = QueryParamsBuilder::<TestModel>::new()
+            .with_cursor(prev_cursor.unwrap())
+            .build();
+        let prev_builder = test_keyset_builder(prev_params).with_cursor_signing_key("secret");
+        let position = prev_builder.resolve_keyset_position().unwrap();
+        let mut arguments = PgArguments::default();
+
+        assert_eq!(position.direction, QueryCursorDirection::Previous);
+        assert_eq!(position.tie_breaker_value, "1");
+        assert!(prev_builder
+            .build_keyset_condition(&position, &mut arguments)
+            .unwrap()
+            .unwrap()
+            .contains(") > ($1"));
+        assert_eq!(
+            prev_builder.build_order_clause(true),
+            " ORDER BY \"created_at\" ASC, \"id\" ASC"
+        );
+    }
+
+    #[test]
+    fn test_cursor_rejected_for_other_filters() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter("name", Some("john"))
+            .build();
+        let builder = test_keyset_builder(params);
+        let (next_cursor, _) = builder.build_cursors(&test_records(), None, true).unwrap();
+
+        let swapped_params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter("name", Some("jane"))
+            .with_cursor(next_cursor.unwrap())
+            .build();
+        let swapped_builder = test_keyset_builder(swapped_params);
+
+        assert!(swapped_builder.resolve_keyset_position().is_none());
+    }
+
+    #[test]
+    fn test_invalid_cursor_rejected_in_strict_mode() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_cursor("not-a-cursor")
+            .build();
+        let builder = test_keyset_builder(params);
+        assert!(builder.validate_cursor().is_ok());
+
+        let strict_builder = builder.strict();
+        assert!(matches!(
+            strict_builder.validate_cursor(),
+            Err(PaginationError::InvalidCursor)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_sort_column_rejected_in_strict_mode() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sort("ctid", QuerySortDirection::Ascending)
+            .build();
+        let mut builder = test_builder(params).strict();
+
+        assert!(matches!(
+            builder.validate_sort(),
+            Err(PaginationError::InvalidSortColumn(column)) if column == "ctid"
+        ));
+    }
+
+    #[test]
+    fn test_cursor_rejected_without_signature() {
+        let params = QueryParamsBuilder::<TestModel>::new().build();
+        let builder = test_keyset_builder(params);
+        let (next_cursor, _) = builder.build_cursors(&test_records(), None, true).unwrap();
+
+        let forged_params = QueryParamsBuilder::<TestModel>::new()
+            .with_cursor(next_cursor.unwrap())
+            .build();
+        let signed_builder = test_keyset_builder(forged_params).with_cursor_signing_key("secret");
+
+        assert!(signed_builder.resolve_keyset_position().is_none());
+    }
+
+    #[test]
+    fn test_no_cursors_for_partial_first_page() {
+        let params = QueryParamsBuilder::<TestModel>::new().build();
+        let builder = test_keyset_builder(params);
+
+        assert_eq!(
+            builder
+                .build_cursors(&test_records()[..4], None, false)
+                .unwrap(),
+            (None, None)
+        );
+        assert_eq!(
+            builder.build_cursors(&[], None, false).unwrap(),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn test_null_sort_value_on_page_boundary() {
+        #[derive(Debug, Default, Serialize, FromRow)]
+        struct EventModel {
+            id: i64,
+            finished_at: Option<String>,
+        }
+
+        let params = QueryParamsBuilder::<EventModel>::new()
+            .with_sort("finished_at", QuerySortDirection::Ascending)
+            .build();
+        let builder = PaginatedQueryBuilder::new(sqlx::query_as::<Postgres, EventModel>(
+            "SELECT * FROM events",
+        ))
+        .with_params(params)
+        .with_keyset_pagination("id");
+        let records = vec![
+            EventModel {
+                id: 1,
+                finished_at: Some("2024-11-01T00:00:00Z".to_string()),
+            },
+            EventModel {
+                id: 2,
+                finished_at: None,
+            },
+        ];
+
+        // The next page can't be positioned after a NULL, it fails instead of losing its cursor
+        assert!(matches!(
+            builder.build_cursors(&records, None, true),
+            Err(PaginationError::InvalidConfiguration(reason)) if reason.contains("finished_at")
+        ));
+        assert_eq!(
+            builder.build_cursors(&records, None, false).unwrap(),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn test_page_links() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(2, 10)
+            .build();
+        let builder = test_builder(params);
+
+        assert_eq!(builder.build_page_links(None, true), (true, true));
+        assert_eq!(builder.build_page_links(None, false), (false, true));
+
+        let builder = test_builder(QueryParamsBuilder::<TestModel>::new().build());
+        assert_eq!(builder.build_page_links(None, false), (false, false));
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(3, 10)
+            .build();
+        let builder = test_keyset_builder(params);
+        assert_eq!(builder.build_page_links(None, true), (true, false));
+    }
+}
+
+#[cfg(all(test, feature = "postgres"))]
+mod postgres_tests {
+    use super::*;
+    use crate::QueryParamsBuilder;
+    use chrono::{DateTime, Utc};
+    use sqlx::postgres::{PgArguments, PgPool, PgPoolOptions};
+
+    #[derive(Debug, Default, Serialize, FromRow)]
+    struct ArticleModel {
+        id: i64,
+        title: String,
+        published_at: DateTime<Utc>,
+    }
+
+    /// Connects to the database of `DATABASE_URL` and seeds a temporary `articles` table.
+    async fn test_postgres_pool(titles: &[&str]) -> PgPool {
+        let url =
+            std::env::var("DATABASE_URL").expect("DATABASE_URL is set to a Postgres database");
+        // A single connection keeps the temporary table visible to every query
+        let pool = PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&url)
+            .await
+            .unwrap();
+
+        sqlx::query(
+            "CREATE TEMPORARY TABLE articles (id BIGINT PRIMARY KEY, title TEXT NOT NULL, \
+             published_at TIMESTAMPTZ NOT NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        // Articles are published an hour apart, in the order of their ids
+        for (id, title) in titles.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO articles (id, title, published_at) \
+                 VALUES ($1, $2, TIMESTAMPTZ '2024-11-01 00:00:00Z' + $1 * INTERVAL '1 hour')",
+            )
+            .bind(id as i64 + 1)
+            .bind(title)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        sqlx::query("ANALYZE articles")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        pool
+    }
+
+    fn test_builder() -> PaginatedQueryBuilder<'static, ArticleModel, Postgres, PgArguments> {
+        PaginatedQueryBuilder::new(sqlx::query_as::<Postgres, ArticleModel>(
+            "SELECT * FROM articles",
+        ))
+        .with_sort_fallback("id")
+    }
+
+    fn record_ids(response: &PaginatedResponse<ArticleModel>) -> Vec<i64> {
+        response.records.iter().map(|record| record.id).collect()
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a Postgres database at DATABASE_URL"]
+    async fn test_postgres_fetch_paginated_estimated_totals() {
+        let titles: Vec<String> = (1..=200).map(|id| format!("article {}", id)).collect();
+        let titles: Vec<&str> = titles.iter().map(String::as_str).collect();
+        let pool = test_postgres_pool(&titles).await;
+
+        let response = test_builder()
+            .with_params(
+                QueryParamsBuilder::<ArticleModel>::new()
+                    .with_pagination(2, 10)
+                    .with_sort("id", QuerySortDirection::Ascending)
+                    .build(),
+            )
+            .with_totals_mode(TotalsMode::Estimated)
+            .fetch_paginated(&pool)
+            .await
+            .unwrap();
+
+        // The plan of an analyzed table estimates every row of an unfiltered query
+        assert_eq!(record_ids(&response), (11..=20).collect::<Vec<_>>());
+        assert_eq!(response.total, Some(200));
+        assert_eq!(response.total_pages, Some(20));
+        assert_eq!(response.total_exact, Some(false));
+
+        let response = test_builder()
+            .with_params(
+                QueryParamsBuilder::<ArticleModel>::new()
+                    .with_filter("id", Some("7"))
+                    .build(),
+            )
+            .with_totals_mode(TotalsMode::Estimated)
+            .fetch_paginated(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(record_ids(&response), vec![7]);
+        assert_eq!(response.total, Some(1));
+        assert_eq!(response.total_exact, Some(false));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a Postgres database at DATABASE_URL"]
+    async fn test_postgres_fetch_paginated_full_text_search() {
+        let pool = test_postgres_pool(&[
+            "Cooking with cast iron",
+            "Rust ownership explained",
+            "Rust for Rust developers: rusty borrowing in Rust",
+            "Gardening in spring",
+        ])
+        .await;
+
+        let response = test_builder()
+            .with_params(
+                QueryParamsBuilder::<ArticleModel>::new()
+                    .with_search("rust", vec!["title"])
+                    .build(),
+            )
+            .with_full_text_search(FullTextSearch::new("english").with_rank_sort())
+            .fetch_paginated(&pool)
+            .await
+            .unwrap();
+
+        // Stemming matches "rusty", the more frequent match ranks first
+        assert_eq!(record_ids(&response), vec![3, 2]);
+        assert_eq!(response.total, Some(2));
+        assert_eq!(response.total_exact, Some(true));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a Postgres database at DATABASE_URL"]
+    async fn test_postgres_fetch_paginated_keyset_cursors() {
+        let titles: Vec<String> = (1..=25).map(|id| format!("article {}", id)).collect();
+        let titles: Vec<&str> = titles.iter().map(String::as_str).collect();
+        let pool = test_postgres_pool(&titles).await;
+        let fetch = |params: QueryParams<'static, ArticleModel>| {
+            test_builder()
+                .with_column_registry(
+                    ColumnRegistry::from_struct_fields::<ArticleModel>()
+                        .with_column_type("id", ColumnType::BigInt)
+                        .with_column_type("published_at", ColumnType::TimestampTz),
+                )
+                .with_params(params)
+                .with_keyset_pagination("id")
+                .fetch_paginated(&pool)
+        };
+        // Cursors are only valid for the sort they were issued with
+        let params = |cursor: Option<String>| {
+            let builder = QueryParamsBuilder::<ArticleModel>::new()
+                .with_sort("published_at", QuerySortDirection::Descending);
+            match cursor {
+                Some(cursor) => builder.with_cursor(cursor).build(),
+                None => builder.build(),
+            }
+        };
+
+        let first = fetch(params(None)).await.unwrap();
+        assert_eq!(record_ids(&first), (16..=25).rev().collect::<Vec<_>>());
+        assert!(first.prev_cursor.is_none());
+
+        // The cursor values are compared with a timestamptz column
+        let second = fetch(params(first.next_cursor)).await.unwrap();
+        assert_eq!(record_ids(&second), (6..=15).rev().collect::<Vec<_>>());
+
+        let back = fetch(params(second.prev_cursor)).await.unwrap();
+        assert_eq!(record_ids(&back), (16..=25).rev().collect::<Vec<_>>());
+        assert!(back.prev_cursor.is_none());
+
+        let last = fetch(params(second.next_cursor)).await.unwrap();
+        assert_eq!(record_ids(&last), (1..=5).rev().collect::<Vec<_>>());
+        assert!(last.next_cursor.is_none());
+    }
+
+    /// Needs the `pg_trgm` extension, which CI creates along with the database.
+    #[tokio::test]
+    #[ignore = "needs a Postgres database at DATABASE_URL"]
+    async fn test_postgres_fetch_paginated_trigram_search() {
+        let pool = test_postgres_pool(&["postgres tuning", "postgresql", "mysql", "postgre"]).await;
+
+        let response = test_builder()
+            .with_params(
+                QueryParamsBuilder::<ArticleModel>::new()
+                    .with_search("postgres", vec!["title"])
+                    .build(),
+            )
+            .with_trigram_search(TrigramSearch::new().with_threshold(0.3).with_rank_sort())
+            .fetch_paginated(&pool)
+            .await
+            .unwrap();
+
+        // Misspellings match by similarity, the most similar title first
+        assert_eq!(record_ids(&response), vec![4, 2, 1]);
+        assert_eq!(response.total, Some(3));
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod sqlite_tests {
+    use super::*;
+    use crate::paginated_query_as::internal::encode_query_component;
+    use crate::{
+        ColumnType, FilterExpr, PaginatedColumn, QueryFilterOperator, QueryParamsBuilder,
+        QuerySearchMode,
+    };
+    use sqlx::sqlite::SqlitePoolOptions;
+    use sqlx::{Sqlite, SqlitePool};
+
+    #[derive(Debug, Default, Serialize, FromRow)]
+    struct TestModel {
+        id: i64,
+        name: String,
+        created_at: String,
+    }
+
+    async fn test_sqlite_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, created_at TEXT)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        for id in 1..=25 {
+            sqlx::query("INSERT INTO users (id, name, created_at) VALUES (?, ?, ?)")
+                .bind(id)
+                .bind(format!("user {}", id))
+                .bind(format!("2024-11-{:02}T12:30:12Z", 30 - id))
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated() {
+        let pool = test_sqlite_pool().await;
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(2, 10)
+            .with_search("12:30", vec!["name", "created_at"])
+            .build();
+
+        let response =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params)
+                .fetch_paginated(&pool)
+                .await
+                .unwrap();
+
+        assert_eq!(response.total, Some(25));
+        assert_eq!(response.total_pages, Some(3));
+        assert_eq!(
+            response.records.iter().map(|r| r.id).collect::<Vec<_>>(),
+            (11..=20).collect::<Vec<_>>()
+        );
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter("name", Some("user 7"))
+            .build();
+        let response =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params)
+                .fetch_paginated(&pool)
+                .await
+                .unwrap();
+
+        assert_eq!(response.total, Some(1));
+        assert_eq!(response.records[0].id, 7);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_search_modes() {
+        let pool = test_sqlite_pool().await;
+        let fetch_ids = |search: &str, search_mode: QuerySearchMode| {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search(search, vec!["name"])
+                .with_search_mode(search_mode)
+                .build();
+            let pool = &pool;
+            async move {
+                PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>(
+                    "SELECT * FROM users",
+                ))
+                .with_params(params)
+                .fetch_paginated(pool)
+                .await
+                .unwrap()
+                .records
+                .iter()
+                .map(|record| record.id)
+                .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(
+            fetch_ids("USER 2", QuerySearchMode::StartsWith).await,
+            vec![2, 20, 21, 22, 23, 24, 25]
+        );
+        assert_eq!(
+            fetch_ids("5", QuerySearchMode::EndsWith).await,
+            vec![5, 15, 25]
+        );
+        assert_eq!(fetch_ids("user 1", QuerySearchMode::Exact).await, vec![1]);
+        assert_eq!(fetch_ids("2", QuerySearchMode::WholeWord).await, vec![2]);
+
+        // Wildcards match literally
+        assert!(fetch_ids("user_1", QuerySearchMode::Contains)
+            .await
+            .is_empty());
+        assert!(fetch_ids("%", QuerySearchMode::Contains).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_weighted_search() {
+        let pool = test_sqlite_pool().await;
+        let registry = ColumnRegistry::from_struct_fields::<TestModel>()
+            .with_column_type("id", ColumnType::BigInt)
+            .with_search_mode("id", QuerySearchMode::Exact)
+            .with_search_weight("id", 3)
+            .with_search_mode("name", QuerySearchMode::WholeWord)
+            .with_search_weight("name", 5);
+        let fetch_ids = |search: &str, search_columns: Vec<&str>| {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search(search, search_columns)
+                .build();
+            let registry = registry.clone();
+            let pool = &pool;
+            async move {
+                PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>(
+                    "SELECT * FROM users",
+                ))
+                .with_params(params)
+                .with_column_registry(registry)
+                .fetch_paginated(pool)
+                .await
+                .unwrap()
+                .records
+                .iter()
+                .map(|record| record.id)
+                .collect::<Vec<_>>()
+            }
+        };
+
+        // Every row matches the creation time, the whole word match in the name ranks first
+        assert_eq!(
+            fetch_ids("12", vec!["name", "created_at"]).await,
+            vec![12, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+        );
+        assert_eq!(
+            fetch_ids("7", vec!["id", "created_at"]).await,
+            vec![7, 3, 13, 23]
+        );
+        assert!(fetch_ids("seven", vec!["id"]).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_filter_operators() {
+        let pool = test_sqlite_pool().await;
+        let fetch_ids = |params: QueryParams<'static, TestModel>| async {
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params)
+                .fetch_paginated(&pool)
+                .await
+                .unwrap()
+                .records
+                .iter()
+                .map(|record| record.id)
+                .collect::<Vec<_>>()
+        };
+
+        let ids = fetch_ids(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_filter("id[gte]", Some("5"))
+                .with_filter("id__lt", Some("9"))
+                .build(),
+        )
+        .await;
+        assert_eq!(ids, vec![5, 6, 7, 8]);
+
+        let ids = fetch_ids(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_filter_operator("id", QueryFilterOperator::In, Some("3,1,20"))
+                .with_filter_operator("name", QueryFilterOperator::Ne, Some("user 20"))
+                .build(),
+        )
+        .await;
+        assert_eq!(ids, vec![1, 3]);
+
+        let ids = fetch_ids(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_filter_operator("name", QueryFilterOperator::Ilike, Some("USER 2"))
+                .with_filter_operator("id", QueryFilterOperator::Between, Some("20,22"))
+                .with_filter_operator("created_at", QueryFilterOperator::IsNotNull, None::<String>)
+                .build(),
+        )
+        .await;
+        assert_eq!(ids, vec![20, 21, 22]);
+
+        // Wildcards of like values match literally
+        let ids = fetch_ids(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_filter_operator("name", QueryFilterOperator::Like, Some("user_1"))
+                .build(),
+        )
+        .await;
+        assert!(ids.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_filter_expr() {
+        let pool = test_sqlite_pool().await;
+        let fetch = |params: QueryParams<'static, TestModel>| async {
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params)
+                .fetch_paginated(&pool)
+                .await
+        };
+
+        // id is 2 or 24, or name is like "user 1" but not id 1, and id isn't 12
+        let flat_params: FlatQueryParams = serde_urlencoded::from_str(&format!(
+            "id[ne]=12&filter={}",
+            encode_query_component(
+                &serde_json::json!({
+                    "or": [
+                        { "column": "id", "operator": "in", "value": [2, 24] },
+                        { "and": [
+                            { "column": "name", "operator": "like", "value": "user 1" },
+                            { "not": { "column": "id", "value": 1 } },
+                        ] },
+                    ]
+                })
+                .to_string()
+            )
+        ))
+        .unwrap();
+        let response = fetch(flat_params.into()).await.unwrap();
+        let ids: Vec<i64> = response.records.iter().map(|record| record.id).collect();
+        assert_eq!(ids, vec![2, 10, 11, 13, 14, 15, 16, 17, 18, 19]);
+        assert_eq!(response.total, Some(11));
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter_expr(FilterExpr::Or(vec![
+                FilterExpr::leaf("id", QueryFilterOperator::Lt, Some("3")),
+                FilterExpr::leaf("email", QueryFilterOperator::IsNull, None::<String>),
+            ]))
+            .build();
+        assert!(matches!(
+            fetch(params).await,
+            Err(PaginationError::InvalidColumn(column)) if column == "email"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_sort_fallback() {
+        let pool = test_sqlite_pool().await;
+        let params = || {
+            QueryParamsBuilder::<TestModel>::new()
+                .with_sort("rowid", QuerySortDirection::Ascending)
+                .build()
+        };
+
+        let response =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params())
+                .fetch_paginated(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            response.records.iter().map(|r| r.id).collect::<Vec<_>>(),
+            (16..=25).rev().collect::<Vec<_>>()
+        );
+
+        let result =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params())
+                .disable_sort_fallback()
+                .fetch_paginated(&pool)
+                .await;
+        assert!(matches!(
+            result,
+            Err(PaginationError::InvalidSortColumn(column)) if column == "rowid"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_strict() {
+        let pool = test_sqlite_pool().await;
+        let fetch = |params: QueryParams<'static, TestModel>| {
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params)
+                .strict()
+                .fetch_paginated(&pool)
+        };
+
+        let flat_params = |query: &str| -> FlatQueryParams {
+            serde_json::from_value(serde_json::from_str(query).unwrap()).unwrap()
+        };
+
+        let result = fetch(flat_params(r#"{"email": "john@example.com"}"#).into()).await;
+        assert!(matches!(
+            result,
+            Err(PaginationError::InvalidColumn(column)) if column == "email"
+        ));
+
+        let result = fetch(flat_params(r#"{"name[unknown]": "user 7"}"#).into()).await;
+        assert!(matches!(
+            result,
+            Err(PaginationError::InvalidColumn(column)) if column == "name[unknown]"
+        ));
+
+        let response = fetch(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_filter("name", Some("user 7"))
+                .build(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.total, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_custom_query_builders() {
+        let pool = test_sqlite_pool().await;
+        let flat_params: FlatQueryParams = serde_json::from_value(serde_json::json!({
+            "name": "user 7",
+            "email": "john@example.com",
+        }))
+        .unwrap();
+        let params = QueryParams::<TestModel>::from(flat_params);
+
+        let response =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params.clone())
+                .with_query_builder(|params| {
+                    QueryBuilder::<TestModel, Sqlite>::new()
+                        .with_filters(params)
+                        .build()
+                })
+                .fetch_paginated(&pool)
+                .await
+                .unwrap();
+        assert_eq!(response.total, Some(1));
+
+        let result =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params)
+                .with_fallible_query_builder(|params| {
+                    QueryBuilder::<TestModel, Sqlite>::new()
+                        .strict()
+                        .with_filters(params)
+                        .try_build()
+                })
+                .fetch_paginated(&pool)
+                .await;
+        assert!(matches!(
+            result,
+            Err(PaginationError::InvalidColumn(column)) if column == "email"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_column_types() {
+        let pool = test_sqlite_pool().await;
+        let registry = ColumnRegistry::from_struct_fields::<TestModel>()
+            .with_column_type("id", ColumnType::Integer)
+            .with_column_type("name", ColumnType::Text);
+        let fetch = |params: QueryParams<'static, TestModel>| {
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params)
+                .with_column_registry(registry.clone())
+                .fetch_paginated(&pool)
+        };
+
+        let response = fetch(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_filter_operator("id", QueryFilterOperator::In, Some("+3, 5"))
+                .build(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.total, Some(2));
+
+        let result = fetch(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_filter_operator("id", QueryFilterOperator::Gt, Some("three"))
+                .build(),
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(PaginationError::InvalidValue { column, .. }) if column == "id"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_qualified_columns() {
+        let pool = test_sqlite_pool().await;
+        let registry = ColumnRegistry::new(vec![
+            PaginatedColumn {
+                column: "u.id".to_string(),
+                column_type: Some(ColumnType::Integer),
+                ..PaginatedColumn::new("id")
+            },
+            PaginatedColumn {
+                column: "u.name".to_string(),
+                ..PaginatedColumn::new("name")
+            },
+            PaginatedColumn::new("created_at"),
+        ]);
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_search("user 1", vec!["name"])
+            .with_filter_operator("id", QueryFilterOperator::Lte, Some("12"))
+            .with_sort("name", QuerySortDirection::Descending)
+            .build();
+
+        let response = PaginatedQueryBuilder::from_column_registry(
+            sqlx::query_as::<Sqlite, TestModel>("SELECT u.* FROM users u"),
+            registry,
+        )
+        .with_params(params)
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(response.total, Some(4));
+        assert_eq!(
+            response.records.iter().map(|r| r.id).collect::<Vec<_>>(),
+            vec![12, 11, 10, 1]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_in_transaction() {
+        let pool = test_sqlite_pool().await;
+        let mut transaction = pool.begin().await.unwrap();
+
+        sqlx::query("INSERT INTO users (id, name, created_at) VALUES (26, 'user 26', '2024-12-01T00:00:00Z')")
+            .execute(&mut *transaction)
+            .await
+            .unwrap();
+
+        let response =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(QueryParamsBuilder::<TestModel>::new().build())
+                .fetch_paginated(&mut *transaction)
+                .await
+                .unwrap();
+        assert_eq!(response.total, Some(26));
+
+        let response =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(QueryParamsBuilder::<TestModel>::new().build())
+                .fetch_paginated(&mut transaction)
+                .await
+                .unwrap();
+        assert_eq!(response.records[0].id, 26);
+
+        transaction.rollback().await.unwrap();
+
+        let mut connection = pool.acquire().await.unwrap();
+        let response =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(QueryParamsBuilder::<TestModel>::new().build())
+                .fetch_paginated(&mut *connection)
+                .await
+                .unwrap();
+        assert_eq!(response.total, Some(25));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_window_totals() {
+        let pool = test_sqlite_pool().await;
+        let fetch = |params: QueryParams<'static, TestModel>| {
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params)
+                .with_window_totals_count()
+                .fetch_paginated(&pool)
+        };
+
+        let response = fetch(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_pagination(2, 10)
+                .with_sort("id", QuerySortDirection::Ascending)
+                .build(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.total, Some(25));
+        assert_eq!(response.total_pages, Some(3));
+        assert_eq!(
+            response.records.iter().map(|r| r.id).collect::<Vec<_>>(),
+            (11..=20).collect::<Vec<_>>()
+        );
+
+        let response = fetch(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_pagination(4, 10)
+                .build(),
+        )
+        .await
+        .unwrap();
+        assert!(response.records.is_empty());
+        assert_eq!(response.total, Some(25));
+
+        let response = fetch(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_filter("name", Some("unknown"))
+                .build(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.total, Some(0));
+        assert_eq!(response.total_pages, Some(0));
+
+        let first =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(QueryParamsBuilder::<TestModel>::new().build())
+                .with_keyset_pagination("id")
+                .with_window_totals_count()
+                .fetch_paginated(&pool)
+                .await
+                .unwrap();
+        let second =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(
+                    QueryParamsBuilder::<TestModel>::new()
+                        .with_cursor(first.next_cursor.unwrap())
+                        .build(),
+                )
+                .with_keyset_pagination("id")
+                .with_window_totals_count()
+                .fetch_paginated(&pool)
+                .await
+                .unwrap();
+        assert_eq!(second.total, Some(25));
+        assert_eq!(second.records.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_window_totals_positional_rows() {
+        let pool = test_sqlite_pool().await;
+        let registry = ColumnRegistry::new(vec![
+            PaginatedColumn::new("id"),
+            PaginatedColumn::new("name"),
+        ]);
+
+        let response = PaginatedQueryBuilder::from_column_registry(
+            sqlx::query_as::<Sqlite, (i64, String)>("SELECT id, name FROM users"),
+            registry.clone(),
+        )
+        .with_params(
+            QueryParamsBuilder::<(i64, String)>::from_column_registry(registry.clone())
+                .with_pagination(2, 10)
+                .with_sort("id", QuerySortDirection::Ascending)
+                .build(),
+        )
+        .with_window_totals_count()
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+        assert_eq!(response.total, Some(25));
+        assert_eq!(response.records.len(), 10);
+        assert_eq!(response.records[0], (11, "user 11".to_string()));
+        assert_eq!(response.records[9], (20, "user 20".to_string()));
+
+        let result = PaginatedQueryBuilder::from_column_registry(
+            sqlx::query_as::<Sqlite, (i64, String)>("SELECT id, name, 0 AS __total FROM users"),
+            registry,
+        )
+        .with_sort_fallback("id")
+        .with_window_totals_count()
+        .fetch_paginated(&pool)
+        .await;
+        assert!(matches!(
+            result,
+            Err(PaginationError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_totals_modes() {
+        let pool = test_sqlite_pool().await;
+        let fetch = |totals_mode: TotalsMode| {
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(QueryParamsBuilder::<TestModel>::new().build())
+                .with_totals_mode(totals_mode)
+                .fetch_paginated(&pool)
+        };
+
+        let response = fetch(TotalsMode::Exact).await.unwrap();
+        assert_eq!(response.total, Some(25));
+        assert_eq!(response.total_exact, Some(true));
+
+        let response = fetch(TotalsMode::ExactUpTo(20)).await.unwrap();
+        assert_eq!(response.total, Some(20));
+        assert_eq!(response.total_pages, Some(2));
+        assert_eq!(response.total_exact, Some(false));
+
+        let response = fetch(TotalsMode::ExactUpTo(25)).await.unwrap();
+        assert_eq!(response.total, Some(25));
+        assert_eq!(response.total_exact, Some(true));
+
+        // SQLite has no planner estimate, the records are counted exactly
+        let response = fetch(TotalsMode::Estimated).await.unwrap();
+        assert_eq!(response.total, Some(25));
+        assert_eq!(response.total_exact, Some(true));
+
+        let response = fetch(TotalsMode::None).await.unwrap();
+        assert_eq!(response.total, None);
+        assert_eq!(response.total_exact, None);
+        assert_eq!(response.records.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_page_links() {
+        let pool = test_sqlite_pool().await;
+        let fetch = |page: i64, page_size: i64| {
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(
+                    QueryParamsBuilder::<TestModel>::new()
+                        .with_pagination(page, page_size)
+                        .build(),
+                )
+                .disable_totals_count()
+                .fetch_paginated(&pool)
+        };
+
+        let response = fetch(1, 10).await.unwrap();
+        assert_eq!(response.records.len(), 10);
+        assert!(response.has_next_page);
+        assert!(!response.has_previous_page);
+        assert_eq!(response.total, None);
+        let pagination = response.pagination.unwrap();
+        assert_eq!((pagination.page, pagination.page_size), (1, 10));
+
+        let response = fetch(3, 10).await.unwrap();
+        assert_eq!(response.records.len(), 5);
+        assert!(!response.has_next_page);
+        assert!(response.has_previous_page);
+
+        // The last page is full, only the extra row tells there is no next page
+        let response = fetch(1, 25).await.unwrap();
+        assert_eq!(response.records.len(), 25);
+        assert!(!response.has_next_page);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_links() {
+        let pool = test_sqlite_pool().await;
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(2, 10)
+            .with_sort("name", QuerySortDirection::Ascending)
+            .with_filter_operator("id", QueryFilterOperator::Gt, Some("0"))
+            .build();
+
+        let response =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params.clone())
+                .with_links("/users")
+                .fetch_paginated(&pool)
+                .await
+                .unwrap();
+        let links = response.links.unwrap();
+        let query = "sort=name&id%5Bgt%5D=0";
+
+        assert_eq!(links.first, format!("/users?{}", query));
+        assert_eq!(links.prev, Some(format!("/users?{}", query)));
+        assert_eq!(links.next, Some(format!("/users?page=3&{}", query)));
+        assert_eq!(links.last, Some(format!("/users?page=3&{}", query)));
+
+        let response =
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params)
+                .disable_totals_count()
+                .fetch_paginated(&pool)
+                .await
+                .unwrap();
+        assert!(response.links.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_fetch_paginated_keyset() {
+        let pool = test_sqlite_pool().await;
+        let fetch_page = |params: QueryParams<'static, TestModel>| {
+            PaginatedQueryBuilder::new(sqlx::query_as::<Sqlite, TestModel>("SELECT * FROM users"))
+                .with_params(params)
+                .with_keyset_pagination("id")
+                .with_links("/users")
+                .disable_totals_count()
+                .fetch_paginated(&pool)
+        };
+
+        let first = fetch_page(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_pagination(1, 10)
+                .build(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            first.records.iter().map(|r| r.id).collect::<Vec<_>>(),
+            (1..=10).collect::<Vec<_>>()
+        );
+
+        let second = fetch_page(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_pagination(1, 10)
+                .with_cursor(first.next_cursor.unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            second.records.iter().map(|r| r.id).collect::<Vec<_>>(),
+            (11..=20).collect::<Vec<_>>()
+        );
+        let cursor_link = |cursor: &Option<String>| {
+            format!(
+                "/users?cursor={}",
+                encode_query_component(cursor.as_ref().unwrap())
+            )
+        };
+        let links = second.links.as_ref().unwrap();
+        assert_eq!(links.first, "/users");
+        assert_eq!(links.prev, Some(cursor_link(&second.prev_cursor)));
+        assert_eq!(links.next, Some(cursor_link(&second.next_cursor)));
+        assert_eq!(links.last, None);
+
+        let previous = fetch_page(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_pagination(1, 10)
+                .with_cursor(second.prev_cursor.unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            previous.records.iter().map(|r| r.id).collect::<Vec<_>>(),
+            (1..=10).collect::<Vec<_>>()
+        );
     }
 }
@@ -1,16 +1,3 @@
-#[cfg(feature = "postgres")]
-mod postgres_query_builder;
 mod query_builder;
 
-#[cfg(feature = "sqlite")]
-mod sqlite_query_builder;
-
-#[allow(unused_imports)]
-#[cfg(feature = "postgres")]
-pub use postgres_query_builder::*;
-
-#[allow(unused_imports)]
-#[cfg(feature = "sqlite")]
-pub use sqlite_query_builder::*;
-
 pub use query_builder::*;
@@ -1,27 +1,196 @@
-use crate::paginated_query_as::internal::{quote_identifier, ColumnProtection, QueryDialect};
-use crate::QueryParams;
+use crate::paginated_query_as::internal::{
+    get_search_condition, parse_filter_key, split_filter_values, ColumnProtection, QueryDatabase,
+    QueryDialect,
+};
+#[cfg(feature = "postgres")]
+use crate::paginated_query_as::PostgresSearch;
+use crate::{
+    ColumnRegistry, ColumnType, FilterExpr, PaginatedModel, PaginationError, QueryFilterOperator,
+    QueryParams, QuerySearchMode,
+};
+#[cfg(feature = "postgres")]
+use crate::{FullTextSearch, TrigramSearch};
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+#[cfg(feature = "postgres")]
+use sqlx::Postgres;
 use sqlx::{Arguments, Database, Encode, Type};
 use std::marker::PhantomData;
 
 pub struct QueryBuilder<'q, T, DB: Database> {
     pub conditions: Vec<String>,
     pub arguments: DB::Arguments<'q>,
-    pub(crate) valid_columns: Vec<String>,
+    pub(crate) registry: ColumnRegistry,
     pub(crate) protection: Option<ColumnProtection>,
     pub(crate) protection_enabled: bool,
+    pub(crate) strict: bool,
+    pub(crate) error: Option<PaginationError>,
     pub(crate) dialect: Box<dyn QueryDialect>,
+    #[cfg(feature = "postgres")]
+    pub(crate) postgres_search: Option<PostgresSearch>,
     pub(crate) _phantom: PhantomData<&'q T>,
 }
 
-impl<'q, T, DB> QueryBuilder<'q, T, DB>
+impl<T, DB> Default for QueryBuilder<'_, T, DB>
 where
     T: Default + Serialize,
+    DB: QueryDatabase,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, DB> QueryBuilder<'_, T, DB>
+where
+    T: Default + Serialize,
+    DB: QueryDatabase,
+{
+    /// Creates a new `QueryBuilder` rendering conditions in the dialect of the database.
+    ///
+    /// # Default Settings
+    ///
+    /// - Valid columns are the serialized field names of T
+    /// - Column protection is enabled
+    /// - Strict mode is disabled, invalid columns are skipped
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let query_builder = QueryBuilder::<UserExample, Postgres>::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::from_column_registry(ColumnRegistry::from_struct_fields::<T>())
+    }
+}
+
+impl<T, DB> QueryBuilder<'_, T, DB>
+where
+    T: PaginatedModel,
+    DB: QueryDatabase,
+{
+    /// Creates a new `QueryBuilder` accepting the columns declared by the model.
+    ///
+    /// Columns are used only for what the model allows (sorting, filtering or searching)
+    /// and conditions are rendered against their SQL column, e.g. `u.email`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use sqlx_paginated::{ColumnRegistry, PaginatedColumn, PaginatedModel, QueryBuilder, QueryParamsBuilder};
+    ///
+    /// struct UserExample {
+    ///     email: String
+    /// }
+    ///
+    /// impl PaginatedModel for UserExample {
+    ///     fn column_registry() -> ColumnRegistry {
+    ///         ColumnRegistry::new(vec![PaginatedColumn {
+    ///             column: "u.email".to_string(),
+    ///             ..PaginatedColumn::new("email")
+    ///         }])
+    ///     }
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<UserExample>::for_model()
+    ///     .with_filter("email", Some("john@example.com"))
+    ///     .build();
+    /// let (conditions, _) = QueryBuilder::<UserExample, Postgres>::for_model()
+    ///     .with_filters(&params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["\"u\".\"email\" = $1"]);
+    /// ```
+    pub fn for_model() -> Self {
+        Self::from_column_registry(T::column_registry())
+    }
+}
+
+impl<T, DB> QueryBuilder<'_, T, DB>
+where
+    DB: QueryDatabase,
+{
+    /// Creates a new `QueryBuilder` accepting the columns of the given registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - Columns accepted by the conditions
+    pub fn from_column_registry(registry: ColumnRegistry) -> Self {
+        Self {
+            conditions: Vec::new(),
+            arguments: Default::default(),
+            registry,
+            protection: Some(DB::column_protection()),
+            protection_enabled: true,
+            strict: false,
+            error: None,
+            dialect: DB::dialect(),
+            #[cfg(feature = "postgres")]
+            postgres_search: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<T> QueryBuilder<'_, T, Postgres> {
+    /// Searches with PostgreSQL full-text search instead of LIKE conditions.
+    ///
+    /// # Arguments
+    ///
+    /// * `full_text_search` - The text search configuration and the optional `tsvector` column
+    ///
+    /// # Details
+    ///
+    /// `with_search` then adds a single `<tsvector> @@ websearch_to_tsquery(...)` condition,
+    /// binding the search text as is. Set it before calling `with_search`, it replaces a
+    /// trigram search.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_full_text_search(mut self, full_text_search: FullTextSearch) -> Self {
+        self.postgres_search = Some(PostgresSearch::FullText(full_text_search));
+        self
+    }
+
+    /// Searches with `pg_trgm` trigram similarity instead of LIKE conditions.
+    ///
+    /// # Arguments
+    ///
+    /// * `trigram_search` - The optional similarity threshold
+    ///
+    /// # Details
+    ///
+    /// `with_search` then adds a `column::text % $n` condition per search column, combined with OR
+    /// and binding the search text once. Set it before calling `with_search`, it replaces a
+    /// full-text search.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_trigram_search(mut self, trigram_search: TrigramSearch) -> Self {
+        self.postgres_search = Some(PostgresSearch::Trigram(trigram_search));
+        self
+    }
+}
+
+impl<'q, T, DB> QueryBuilder<'q, T, DB>
+where
     DB: Database,
     String: for<'a> Encode<'a, DB> + Type<DB>,
 {
-    /// Checks if a column exists in the list of valid columns for T struct.
+    /// Checks if a column exists in the column registry of T.
     ///
     /// # Arguments
     ///
@@ -31,19 +200,82 @@ where
     ///
     /// Returns `true` if the column exists in the valid columns list, `false` otherwise.
     pub(crate) fn has_column(&self, column: &str) -> bool {
-        self.valid_columns.contains(&column.to_string())
+        self.registry.contains(column)
     }
 
-    fn is_column_safe(&self, column: &str) -> bool {
-        let column_exists = self.has_column(column);
+    fn is_column_protected(&self, column: &str) -> bool {
+        self.protection_enabled
+            && self
+                .protection
+                .as_ref()
+                .is_some_and(|protection| !protection.is_safe(column))
+    }
+
+    /// Checks that a column can be used for a purpose, e.g. `ColumnRegistry::is_searchable`,
+    /// and isn't blocked by the column protection.
+    fn is_column_safe(&self, column: &str, usable: fn(&ColumnRegistry, &str) -> bool) -> bool {
+        usable(&self.registry, column) && !self.is_column_protected(column)
+    }
 
-        if !self.protection_enabled {
-            return column_exists;
+    /// Renders the quoted SQL column of a field.
+    fn quote_column(&self, column: &str) -> String {
+        self.dialect
+            .quote_identifier(self.registry.column_of(column))
+    }
+
+    /// Records a skipped column, which fails `try_build` in strict mode.
+    fn reject_column(&mut self, column: &str) {
+        if self.strict {
+            let error = self.column_error(column);
+            self.error.get_or_insert(error);
         }
+    }
 
-        match &self.protection {
-            Some(protection) => column_exists && protection.is_safe(column),
-            None => column_exists,
+    /// Returns the error of a column that can't be used, blocked or not a field of T.
+    fn column_error(&self, column: &str) -> PaginationError {
+        if self.has_column(column) && self.is_column_protected(column) {
+            PaginationError::UnsafeColumn(column.to_string())
+        } else {
+            PaginationError::InvalidColumn(column.to_string())
+        }
+    }
+
+    /// Binds a value, recording the failure returned by the database arguments.
+    fn add_argument<V>(&mut self, value: V)
+    where
+        V: 'q + Encode<'q, DB> + Type<DB>,
+    {
+        if let Err(error) = self.arguments.add(value) {
+            self.error
+                .get_or_insert(PaginationError::BindFailure(error));
+        }
+    }
+
+    /// Adds the condition of a PostgreSQL search, full-text or trigram, matching the search text.
+    ///
+    /// # Arguments
+    ///
+    /// * `postgres_search` - The search settings
+    /// * `search` - The search text, bound as is
+    /// * `columns` - The safe search columns, ignored with a full-text vector column
+    #[cfg(feature = "postgres")]
+    fn push_postgres_search(
+        &mut self,
+        postgres_search: &PostgresSearch,
+        search: &str,
+        columns: &[&String],
+    ) {
+        let quoted_columns: Vec<String> = columns
+            .iter()
+            .map(|column| self.quote_column(column))
+            .collect();
+        let placeholder = self.dialect.placeholder(self.arguments.len() + 1);
+
+        if let Some(condition) =
+            postgres_search.condition(self.dialect.as_ref(), &quoted_columns, &placeholder)
+        {
+            self.conditions.push(condition);
+            self.add_argument(search.to_string());
         }
     }
 
@@ -56,9 +288,18 @@ where
     /// # Details
     ///
     /// - Only searches in columns that are both specified and considered safe
-    /// - Creates case-insensitive LIKE conditions with wildcards
+    /// - Creates case-insensitive LIKE conditions matching as set by the search mode,
+    ///   containing the search text by default
+    /// - A column's own search mode, see `with_search_mode`, overrides the one of the params
+    /// - Columns declared with a non-text type are cast to text, except for exact searches
+    ///   comparing typed values, which skip the column when the search text doesn't parse,
+    ///   matching no rows when every column is skipped
+    /// - Escapes the `%` and `_` wildcards of the search text, which matches literally
     /// - Multiple search columns are combined with OR operators
     /// - Empty search text or no valid columns results in no conditions being added
+    /// - With a full-text or trigram search set, see `with_full_text_search` and
+    ///   `with_trigram_search`, their condition replaces the LIKE conditions and the search
+    ///   mode is ignored
     ///
     /// # Returns
     ///
@@ -86,36 +327,60 @@ where
     pub fn with_search(mut self, params: &QueryParams<T>) -> Self {
         if let Some(search) = &params.search.search {
             if let Some(columns) = &params.search.search_columns {
-                let valid_search_columns: Vec<&String> = columns
-                    .iter()
-                    .filter(|column| self.is_column_safe(column))
-                    .collect();
+                let (valid_search_columns, invalid_search_columns): (Vec<&String>, Vec<&String>) =
+                    columns.iter().partition(|column| {
+                        self.is_column_safe(column, ColumnRegistry::is_searchable)
+                    });
+
+                if !search.trim().is_empty() {
+                    for column in invalid_search_columns {
+                        self.reject_column(column);
+                    }
+                }
+
+                #[cfg(feature = "postgres")]
+                if let Some(postgres_search) = self.postgres_search.clone() {
+                    if !search.trim().is_empty() {
+                        self.push_postgres_search(&postgres_search, search, &valid_search_columns);
+                    }
+                    return self;
+                }
 
                 if !valid_search_columns.is_empty() && !search.trim().is_empty() {
-                    let pattern = format!("%{}%", search);
-                    let next_argument = self.arguments.len() + 1;
+                    let mut search_conditions = Vec::new();
 
-                    let search_conditions: Vec<String> = valid_search_columns
-                        .iter()
-                        .map(|column| {
-                            let table_column = self.dialect.quote_identifier(column);
-                            let placeholder = self.dialect.placeholder(next_argument);
-                            format!("LOWER({}) LIKE LOWER({})", table_column, placeholder)
-                        })
-                        .collect();
-
-                    if !search_conditions.is_empty() {
-                        self.conditions
-                            .push(format!("({})", search_conditions.join(" OR ")));
-                        self.arguments.add(pattern).unwrap_or_default();
+                    // Positional placeholders (`?`) can't be reused, so the value is bound per column
+                    for column in valid_search_columns {
+                        let Some((condition, value)) = get_search_condition(
+                            self.dialect.as_ref(),
+                            &self.quote_column(column),
+                            self.registry.column_type(column),
+                            self.registry
+                                .search_mode(column)
+                                .unwrap_or(params.search.search_mode),
+                            search,
+                            self.arguments.len() + 1,
+                        ) else {
+                            continue;
+                        };
+                        search_conditions.push(condition);
+                        self.add_argument(value);
                     }
+
+                    // No column can match, e.g. text searched exactly in numeric columns
+                    if search_conditions.is_empty() {
+                        search_conditions.push("1 = 0".to_string());
+                    }
+
+                    self.conditions
+                        .push(format!("({})", search_conditions.join(" OR ")));
                 }
             }
         }
         self
     }
 
-    /// Adds equality filters to the query based on provided key-value pairs.
+    /// Adds filters to the query based on provided key-value pairs.
     ///
     /// # Arguments
     ///
@@ -123,10 +388,19 @@ where
     ///
     /// # Details
     ///
+    /// - Keys select a column and an optional operator: `price`, `price[gte]` or `price__gte`
+    /// - Supported operators are `eq`, `ne`, `gt`, `gte`, `lt`, `lte`, `like`, `ilike`, `in`,
+    ///   `not_in`, `is_null`, `is_not_null` and `between`
+    /// - `in`, `not_in` and `between` take comma separated values, `between` exactly two
+    /// - `like` and `ilike` match values containing the filter value, `ilike` ignoring case,
+    ///   its `%` and `_` wildcards matching literally as in searches
+    /// - `is_null` and `is_not_null` ignore the filter value
     /// - Only applies filters for columns that exist and are considered safe
     /// - Automatically handles type casting based on the database dialect
-    /// - Skips invalid columns with a warning when tracing is enabled
+    /// - Skips invalid columns and operators with a warning when tracing is enabled
+    /// - Values that don't parse as the declared type of their column match no rows (`1 = 0`)
     /// - Null or empty values are ignored
+    /// - The filter expression of the params, if any, is added last as with `with_filter_expr`
     ///
     /// # Returns
     ///
@@ -137,42 +411,367 @@ where
     /// ```rust
     /// use sqlx::Postgres;
     /// use serde::{Serialize};
-    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    /// use sqlx_paginated::{QueryBuilder, QueryFilterOperator, QueryParamsBuilder};
     ///
     /// #[derive(Serialize, Default)]
-    /// struct UserExample {
-    ///     name: String
+    /// struct ProductExample {
+    ///     name: String,
+    ///     price: i64
     /// }
     ///
-    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
-    ///         .with_search("john", vec!["name", "email"])
+    /// let initial_params = QueryParamsBuilder::<ProductExample>::new()
+    ///         .with_filter("name", Some("Chair"))
+    ///         .with_filter_operator("price", QueryFilterOperator::Gte, Some("10"))
     ///         .build();
     ///
-    /// let query_builder = QueryBuilder::<UserExample, Postgres>::new()
+    /// let query_builder = QueryBuilder::<ProductExample, Postgres>::new()
     ///     .with_filters(&initial_params)
     ///     .build();
     /// ```
-    pub fn with_filters(mut self, params: &'q QueryParams<T>) -> Self {
+    pub fn with_filters(mut self, params: &QueryParams<T>) -> Self {
         for (key, value) in &params.filters {
-            if self.is_column_safe(key) {
-                if let Some(value) = value {
-                    let table_column = self.dialect.quote_identifier(key);
-                    let type_cast = self.dialect.type_cast(value);
-                    let next_argument = self.arguments.len() + 1;
-                    let placeholder = self.dialect.placeholder(next_argument);
-
-                    self.conditions
-                        .push(format!("{} = {}{}", table_column, placeholder, type_cast));
-                    self.arguments.add(value).unwrap_or_default();
+            match parse_filter_key(key) {
+                Some((column, operator))
+                    if self.is_column_safe(column, ColumnRegistry::is_filterable) =>
+                {
+                    if let Some(condition) =
+                        self.build_filter_condition(column, operator, value.as_deref())
+                    {
+                        self.conditions.push(condition);
+                    }
+                }
+                parsed => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(column = %key, "Skipping invalid filter column");
+                    self.reject_column(parsed.map_or(key.as_str(), |(column, _)| column));
                 }
-            } else {
-                #[cfg(feature = "tracing")]
-                tracing::warn!(column = %key, "Skipping invalid filter column");
             }
         }
+
+        match &params.filter {
+            Some(filter) => self.with_filter_expr(filter),
+            None => self,
+        }
+    }
+
+    /// Adds a tree of filter conditions combined with `AND`, `OR` and `NOT`.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The filter expression, e.g. parsed from an OData `$filter`
+    ///
+    /// `with_filters` already adds the filter expression of the params, this adds others.
+    ///
+    /// # Details
+    ///
+    /// - Groups are wrapped in parentheses, and values are bound in the order they're written
+    /// - Leaves are rendered as the filters of `with_filters`, with the same operators
+    /// - Columns that aren't fields of T or are blocked by the column protection, and values
+    ///   not parsing as the declared type of their column, fail `try_build` in strict mode or not
+    /// - Trees nested deeper than 32 levels or with more than 256 leaves fail `try_build` with
+    ///   `PaginationError::InvalidExpression`
+    /// - An invalid tree renders as a single condition matching no rows (`1 = 0`) with `build`,
+    ///   as leaving out part of it would change its meaning
+    /// - Leaves missing the values their operator needs are left out, as empty filters
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{FilterExpr, QueryBuilder, QueryFilterOperator};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct ProductExample {
+    ///     status: String,
+    ///     price: i64
+    /// }
+    ///
+    /// let expr = FilterExpr::And(vec![
+    ///     FilterExpr::leaf("price", QueryFilterOperator::Gt, Some("10")),
+    ///     FilterExpr::Or(vec![
+    ///         FilterExpr::leaf("status", QueryFilterOperator::Eq, Some("active")),
+    ///         FilterExpr::leaf("status", QueryFilterOperator::Eq, Some("trial")),
+    ///     ]),
+    /// ]);
+    /// let (conditions, _) = QueryBuilder::<ProductExample, Postgres>::new()
+    ///     .with_filter_expr(&expr)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     conditions,
+    ///     vec!["(\"price\" > $1::smallint AND (\"status\" = $2 OR \"status\" = $3))"]
+    /// );
+    /// ```
+    pub fn with_filter_expr(mut self, expr: &FilterExpr) -> Self {
+        if let Err(error) = expr
+            .check_limits()
+            .and_then(|()| self.check_filter_expr(expr))
+        {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %error, "Rejecting invalid filter expression");
+            self.error.get_or_insert(error);
+            self.conditions.push("1 = 0".to_string());
+            return self;
+        }
+        if let Some(condition) = self.build_filter_expr(expr) {
+            self.conditions.push(condition);
+        }
         self
     }
 
+    /// Renders a filter expression, binding the values of its leaves.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` when none of its leaves renders a condition
+    fn build_filter_expr(&mut self, expr: &FilterExpr) -> Option<String> {
+        match expr {
+            FilterExpr::And(exprs) => self.build_filter_group(exprs, " AND "),
+            FilterExpr::Or(exprs) => self.build_filter_group(exprs, " OR "),
+            FilterExpr::Not(expr) => Some(format!("NOT ({})", self.build_filter_expr(expr)?)),
+            FilterExpr::Leaf {
+                column,
+                operator,
+                value,
+            } => self.build_filter_condition(column, *operator, value.as_deref()),
+        }
+    }
+
+    /// Checks the leaves of a filter expression before any of its values is bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first leaf whose column isn't a filterable field of T or is
+    /// blocked by the column protection, or whose value doesn't parse as the declared type
+    /// of its column
+    fn check_filter_expr(&self, expr: &FilterExpr) -> Result<(), PaginationError> {
+        match expr {
+            FilterExpr::And(exprs) | FilterExpr::Or(exprs) => exprs
+                .iter()
+                .try_for_each(|expr| self.check_filter_expr(expr)),
+            FilterExpr::Not(expr) => self.check_filter_expr(expr),
+            FilterExpr::Leaf {
+                column,
+                operator,
+                value,
+            } => {
+                if !self.is_column_safe(column, ColumnRegistry::is_filterable) {
+                    return Err(self.column_error(column));
+                }
+
+                let (Some(column_type), Some(value)) = (self.registry.column_type(column), value)
+                else {
+                    return Ok(());
+                };
+                let values = match operator {
+                    QueryFilterOperator::Like
+                    | QueryFilterOperator::Ilike
+                    | QueryFilterOperator::IsNull
+                    | QueryFilterOperator::IsNotNull => return Ok(()),
+                    QueryFilterOperator::In
+                    | QueryFilterOperator::NotIn
+                    | QueryFilterOperator::Between => split_filter_values(value),
+                    _ => vec![value.clone()],
+                };
+
+                match values
+                    .into_iter()
+                    .find(|value| column_type.parse_value(value).is_none())
+                {
+                    Some(value) => Err(PaginationError::InvalidValue {
+                        column: column.clone(),
+                        value,
+                        column_type,
+                    }),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+
+    fn build_filter_group(&mut self, exprs: &[FilterExpr], separator: &str) -> Option<String> {
+        let mut conditions: Vec<String> = exprs
+            .iter()
+            .filter_map(|expr| self.build_filter_expr(expr))
+            .collect();
+
+        match conditions.len() {
+            0 => None,
+            1 => conditions.pop(),
+            _ => Some(format!("({})", conditions.join(separator))),
+        }
+    }
+
+    /// Renders a single filter condition, binding its values.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` when the operator is missing the values it needs, and a condition
+    /// matching no rows when a value doesn't parse as the declared type of the column
+    fn build_filter_condition(
+        &mut self,
+        column: &str,
+        operator: QueryFilterOperator,
+        value: Option<&str>,
+    ) -> Option<String> {
+        let table_column = self.quote_column(column);
+
+        match operator {
+            QueryFilterOperator::IsNull => Some(format!("{} IS NULL", table_column)),
+            QueryFilterOperator::IsNotNull => Some(format!("{} IS NOT NULL", table_column)),
+            QueryFilterOperator::Like => {
+                let pattern = QuerySearchMode::Contains.like_pattern(value?);
+                let placeholder = self.add_filter_argument(column, pattern, false);
+                Some(format!(
+                    "{} LIKE {}{}",
+                    self.text_column(column, table_column),
+                    placeholder,
+                    self.dialect.like_escape_clause()
+                ))
+            }
+            QueryFilterOperator::Ilike => {
+                let pattern = QuerySearchMode::Contains.like_pattern(value?);
+                let placeholder = self.add_filter_argument(column, pattern, false);
+                Some(format!(
+                    "LOWER({}) LIKE LOWER({}){}",
+                    self.text_column(column, table_column),
+                    placeholder,
+                    self.dialect.like_escape_clause()
+                ))
+            }
+            QueryFilterOperator::In | QueryFilterOperator::NotIn => {
+                let values = split_filter_values(value?);
+                if values.is_empty() {
+                    return None;
+                }
+
+                let Some(values) = values
+                    .into_iter()
+                    .map(|value| self.parse_filter_value(column, value))
+                    .collect::<Option<Vec<String>>>()
+                else {
+                    return Some("1 = 0".to_string());
+                };
+                let placeholders: Vec<String> = values
+                    .into_iter()
+                    .map(|value| self.add_filter_argument(column, value, true))
+                    .collect();
+                let keyword = match operator {
+                    QueryFilterOperator::In => "IN",
+                    _ => "NOT IN",
+                };
+
+                Some(format!(
+                    "{} {} ({})",
+                    table_column,
+                    keyword,
+                    placeholders.join(", ")
+                ))
+            }
+            QueryFilterOperator::Between => {
+                let mut values = split_filter_values(value?).into_iter();
+                let (Some(low), Some(high), None) = (values.next(), values.next(), values.next())
+                else {
+                    return None;
+                };
+
+                let (Some(low), Some(high)) = (
+                    self.parse_filter_value(column, low),
+                    self.parse_filter_value(column, high),
+                ) else {
+                    return Some("1 = 0".to_string());
+                };
+                let low_placeholder = self.add_filter_argument(column, low, true);
+                let high_placeholder = self.add_filter_argument(column, high, true);
+
+                Some(format!(
+                    "{} BETWEEN {} AND {}",
+                    table_column, low_placeholder, high_placeholder
+                ))
+            }
+            comparison => {
+                let symbol = match comparison {
+                    QueryFilterOperator::Ne => "<>",
+                    QueryFilterOperator::Gt => ">",
+                    QueryFilterOperator::Gte => ">=",
+                    QueryFilterOperator::Lt => "<",
+                    QueryFilterOperator::Lte => "<=",
+                    _ => "=",
+                };
+                let Some(value) = self.parse_filter_value(column, value?.to_string()) else {
+                    return Some("1 = 0".to_string());
+                };
+                let placeholder = self.add_filter_argument(column, value, true);
+
+                Some(format!("{} {} {}", table_column, symbol, placeholder))
+            }
+        }
+    }
+
+    /// Casts a quoted column to text for pattern matching, unless it's a text column or its
+    /// type is unknown.
+    fn text_column(&self, column: &str, table_column: String) -> String {
+        match self.registry.column_type(column) {
+            Some(column_type) if column_type != ColumnType::Text => {
+                self.dialect.text_cast(&table_column)
+            }
+            _ => table_column,
+        }
+    }
+
+    /// Parses a filter value as the declared type of its column, values of columns without
+    /// a declared type are kept as they are.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` and records `PaginationError::InvalidValue` when the value doesn't parse,
+    /// in strict mode or not. The filter then matches no rows, as skipping it would widen the results
+    fn parse_filter_value(&mut self, column: &str, value: String) -> Option<String> {
+        let Some(column_type) = self.registry.column_type(column) else {
+            return Some(value);
+        };
+
+        let parsed = column_type.parse_value(&value);
+        if parsed.is_none() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %column, value = %value, "Skipping invalid filter value");
+            self.error.get_or_insert(PaginationError::InvalidValue {
+                column: column.to_string(),
+                value,
+                column_type,
+            });
+        }
+        parsed
+    }
+
+    /// Binds a filter value and returns its placeholder, type cast when `typed` is set.
+    ///
+    /// The cast follows the declared type of the column, or is guessed from the value when
+    /// the column has none.
+    fn add_filter_argument(&mut self, column: &str, value: String, typed: bool) -> String {
+        let next_argument = self.arguments.len() + 1;
+        let (placeholder, value) = match (typed, self.registry.column_type(column)) {
+            (false, _) => (self.dialect.placeholder(next_argument), value),
+            (true, Some(column_type)) => (
+                self.dialect
+                    .typed_column_placeholder(next_argument, column_type),
+                self.dialect.typed_column_value(value, column_type),
+            ),
+            (true, None) => (
+                self.dialect.typed_placeholder(next_argument, &value),
+                self.dialect.typed_value(&value),
+            ),
+        };
+        self.add_argument(value);
+
+        placeholder
+    }
+
     /// Adds date range conditions to the query for a specified date column.
     ///
     /// # Arguments
@@ -214,28 +813,35 @@ where
     ///     .with_date_range(&initial_params)
     ///     .build();
     /// ```
-    pub fn with_date_range(mut self, params: &'q QueryParams<T>) -> Self
+    pub fn with_date_range(mut self, params: &QueryParams<T>) -> Self
     where
         DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
     {
         if let Some(date_column) = &params.date_range.date_column {
-            if self.is_column_safe(date_column) {
+            if self.is_column_safe(date_column, ColumnRegistry::is_filterable) {
+                let table_column = self.quote_column(date_column);
+
                 if let Some(after) = params.date_range.date_after {
                     let next_argument = self.arguments.len() + 1;
+                    let placeholder = self.dialect.placeholder(next_argument);
                     self.conditions
-                        .push(format!("{} >= ${}", date_column, next_argument));
-                    self.arguments.add(after).unwrap_or_default();
+                        .push(format!("{} >= {}", table_column, placeholder));
+                    self.add_argument(after);
                 }
 
                 if let Some(before) = params.date_range.date_before {
                     let next_argument = self.arguments.len() + 1;
+                    let placeholder = self.dialect.placeholder(next_argument);
                     self.conditions
-                        .push(format!("{} <= ${}", date_column, next_argument));
-                    self.arguments.add(before).unwrap_or_default();
+                        .push(format!("{} <= {}", table_column, placeholder));
+                    self.add_argument(before);
                 }
-            } else {
+            } else if params.date_range.date_after.is_some()
+                || params.date_range.date_before.is_some()
+            {
                 #[cfg(feature = "tracing")]
                 tracing::warn!(column = %date_column, "Skipping invalid date column");
+                self.reject_column(date_column);
             }
         }
 
@@ -282,18 +888,19 @@ where
         condition: impl Into<String>,
         value: String,
     ) -> Self {
-        if self.is_column_safe(column) {
+        if self.is_column_safe(column, ColumnRegistry::is_filterable) {
             let next_argument = self.arguments.len() + 1;
             self.conditions.push(format!(
-                "{} {} ${}",
-                quote_identifier(column),
+                "{} {} {}",
+                self.quote_column(column),
                 condition.into(),
-                next_argument
+                self.dialect.placeholder(next_argument)
             ));
-            let _ = self.arguments.add(value);
+            self.add_argument(value);
         } else {
             #[cfg(feature = "tracing")]
             tracing::warn!(column = %column, "Skipping invalid condition column");
+            self.reject_column(column);
         }
         self
     }
@@ -406,6 +1013,161 @@ where
         self
     }
 
+    /// Declares the SQL type of a column, casting and parsing its filter values accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The name of the column
+    /// * `column_type` - The SQL type of the column
+    ///
+    /// # Details
+    ///
+    /// - Filter values are cast to the declared type instead of a type guessed from their text
+    /// - Values that don't parse as the declared type fail `try_build` with
+    ///   `PaginationError::InvalidValue`, whether strict mode is enabled or not, and match
+    ///   no rows with `build`
+    /// - Columns that aren't fields of T are ignored
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{ColumnType, QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct AddressExample {
+    ///     zip: String
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<AddressExample>::new()
+    ///         .with_filter("zip", Some("01234"))
+    ///         .build();
+    /// let (conditions, _) = QueryBuilder::<AddressExample, Postgres>::new()
+    ///     .with_column_type("zip", ColumnType::Text)
+    ///     .with_filters(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["\"zip\" = $1::text"]);
+    /// ```
+    pub fn with_column_type(mut self, column: &str, column_type: ColumnType) -> Self {
+        self.registry = self.registry.with_column_type(column, column_type);
+        self
+    }
+
+    /// Sets how the search text matches a column, overriding the search mode of the params.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The name of the column
+    /// * `search_mode` - How the search text matches the column
+    ///
+    /// # Details
+    ///
+    /// - Exact searches in columns declared with a non-text type, see `with_column_type`,
+    ///   compare the search text parsed as the type, skipping the column when it doesn't parse
+    /// - Columns that aren't fields of T are ignored
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{ColumnType, QueryBuilder, QueryParamsBuilder, QuerySearchMode};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct ProductExample {
+    ///     id: i64,
+    ///     sku: String,
+    ///     name: String,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<ProductExample>::new()
+    ///         .with_search("42", vec!["id", "sku", "name"])
+    ///         .build();
+    /// let (conditions, _) = QueryBuilder::<ProductExample, Postgres>::new()
+    ///     .with_column_type("id", ColumnType::BigInt)
+    ///     .with_search_mode("id", QuerySearchMode::Exact)
+    ///     .with_search_mode("sku", QuerySearchMode::StartsWith)
+    ///     .with_search(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     conditions,
+    ///     vec!["(\"id\" = $1::bigint \
+    ///         OR LOWER(\"sku\") LIKE LOWER($2) ESCAPE '!' \
+    ///         OR LOWER(\"name\") LIKE LOWER($3) ESCAPE '!')"]
+    /// );
+    /// ```
+    pub fn with_search_mode(mut self, column: &str, search_mode: QuerySearchMode) -> Self {
+        self.registry = self.registry.with_search_mode(column, search_mode);
+        self
+    }
+
+    /// Sets the weight of a search match in a column, see `ColumnRegistry::with_search_weight`.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The name of the column
+    /// * `weight` - Weight of a match in the column towards the relevance of a row
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_search_weight(mut self, column: &str, weight: u32) -> Self {
+        self.registry = self.registry.with_search_weight(column, weight);
+        self
+    }
+
+    /// Enables strict mode, failing `try_build` on invalid input instead of skipping it.
+    ///
+    /// # Details
+    ///
+    /// - Columns that aren't fields of T fail with `PaginationError::InvalidColumn`
+    /// - Fields blocked by the column protection fail with `PaginationError::UnsafeColumn`
+    /// - Filter keys with an unknown operator fail with `PaginationError::InvalidColumn`
+    /// - Bind failures and filter values not parsing as the declared type of their column
+    ///   fail `try_build` whether strict mode is enabled or not
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{PaginationError, QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///         .with_search("john", vec!["name", "email"])
+    ///         .build();
+    /// let result = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .strict()
+    ///     .with_search(&initial_params)
+    ///     .try_build();
+    ///
+    /// assert!(matches!(result, Err(PaginationError::InvalidColumn(column)) if column == "email"));
+    /// ```
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
     /// Builds the final query conditions and arguments.
     ///
     /// # Returns
@@ -414,6 +1176,9 @@ where
     /// - Vec<String>: List of SQL conditions
     /// - DB::Arguments: Database-specific arguments for parameter binding
     ///
+    /// Invalid columns are left out and filter values not parsing as the declared type of
+    /// their column match no rows, see `try_build` to fail on them instead.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -436,4 +1201,46 @@ where
     pub fn build(self) -> (Vec<String>, DB::Arguments<'q>) {
         (self.conditions, self.arguments)
     }
+
+    /// Builds the final query conditions and arguments, failing on the conditions that
+    /// couldn't be built.
+    ///
+    /// # Returns
+    ///
+    /// Returns the conditions and arguments, as `build` does
+    ///
+    /// # Errors
+    ///
+    /// Returns the first `PaginationError` met while building, i.e. a bind failure, a filter
+    /// value not parsing as the declared type of its column, an invalid filter expression or,
+    /// in strict mode, any invalid or unsafe column
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{PaginationError, QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct ProductExample {
+    ///     price: i64
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<ProductExample>::new()
+    ///         .with_filter("price", Some("cheap"))
+    ///         .build();
+    /// let result = QueryBuilder::<ProductExample, Postgres>::new()
+    ///     .with_column_type("price", sqlx_paginated::ColumnType::BigInt)
+    ///     .with_filters(&initial_params)
+    ///     .try_build();
+    ///
+    /// assert!(matches!(result, Err(PaginationError::InvalidValue { column, .. }) if column == "price"));
+    /// ```
+    pub fn try_build(self) -> Result<(Vec<String>, DB::Arguments<'q>), PaginationError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok((self.conditions, self.arguments)),
+        }
+    }
 }
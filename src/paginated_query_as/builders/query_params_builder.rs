@@ -1,16 +1,19 @@
 use crate::paginated_query_as::internal::{
-    get_struct_field_names, QueryDateRangeParams, QueryPaginationParams, QuerySearchParams,
-    QuerySortParams, DEFAULT_DATE_RANGE_COLUMN_NAME, DEFAULT_MAX_PAGE_SIZE, DEFAULT_MIN_PAGE_SIZE,
-    DEFAULT_PAGE,
+    get_filter_key, is_valid_filter_key, QueryDateRangeParams, QueryKeysetParams,
+    QueryPaginationParams, QuerySearchParams, QuerySortParams, QuerySortTerm,
+    DEFAULT_DATE_RANGE_COLUMN_NAME, DEFAULT_MAX_PAGE_SIZE, DEFAULT_MIN_PAGE_SIZE, DEFAULT_PAGE,
 };
 use crate::paginated_query_as::models::QuerySortDirection;
-use crate::QueryParams;
+use crate::{
+    ColumnRegistry, FilterExpr, PaginatedModel, QueryFilterOperator, QueryParams, QuerySearchMode,
+};
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::collections::HashMap;
 
 pub struct QueryParamsBuilder<'q, T> {
     query: QueryParams<'q, T>,
+    registry: ColumnRegistry,
 }
 
 impl<T: Default + Serialize> Default for QueryParamsBuilder<'_, T> {
@@ -19,7 +22,7 @@ impl<T: Default + Serialize> Default for QueryParamsBuilder<'_, T> {
     }
 }
 
-impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
+impl<T: Default + Serialize> QueryParamsBuilder<'_, T> {
     /// Creates a new `QueryParamsBuilder` with default values.
     ///
     /// Default values include:
@@ -41,8 +44,47 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
     /// let builder = QueryParamsBuilder::<UserExample>::new();
     /// ```
     pub fn new() -> Self {
+        Self::from_column_registry(ColumnRegistry::from_struct_fields::<T>())
+    }
+}
+
+impl<T: PaginatedModel> QueryParamsBuilder<'_, T> {
+    /// Creates a new `QueryParamsBuilder` validating filters against the columns declared by the model.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx_paginated::{ColumnRegistry, PaginatedColumn, PaginatedModel, QueryParamsBuilder};
+    ///
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// impl PaginatedModel for UserExample {
+    ///     fn column_registry() -> ColumnRegistry {
+    ///         ColumnRegistry::new(vec![PaginatedColumn::new("name")])
+    ///     }
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<UserExample>::for_model()
+    ///     .with_filter("name", Some("john"))
+    ///     .build();
+    /// ```
+    pub fn for_model() -> Self {
+        Self::from_column_registry(T::column_registry())
+    }
+}
+
+impl<'q, T> QueryParamsBuilder<'q, T> {
+    /// Creates a new `QueryParamsBuilder` validating filters against the given columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - Columns accepted by the filters
+    pub fn from_column_registry(registry: ColumnRegistry) -> Self {
         Self {
             query: QueryParams::default(),
+            registry,
         }
     }
 
@@ -104,10 +146,54 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         self.query.sort = QuerySortParams {
             sort_column: sort_column.into(),
             sort_direction,
+            sorts: Vec::new(),
         };
         self
     }
 
+    /// Sets multiple sorting terms, applied in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorts` - Column names and directions to sort by, the first one being the primary sort
+    ///
+    /// # Details
+    ///
+    /// Later terms order the rows that share the values of the earlier ones,
+    /// keeping offset pagination stable when the primary sort column has duplicates.
+    /// Keyset pagination only seeks on the primary sort and its tie-breaker.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_sorts(vec![
+    ///         ("created_at", QuerySortDirection::Descending),
+    ///         ("name", QuerySortDirection::Ascending),
+    ///     ])
+    ///     .build();
+    /// ```
+    pub fn with_sorts(
+        mut self,
+        sorts: impl IntoIterator<Item = (impl Into<String>, QuerySortDirection)>,
+    ) -> Self {
+        self.query.sort = QuerySortParams::from_terms(
+            sorts
+                .into_iter()
+                .map(|(column, direction)| QuerySortTerm::new(column, direction))
+                .collect(),
+        );
+        self
+    }
+
     /// Sets search parameters with multiple columns support.
     ///
     /// # Arguments
@@ -138,10 +224,40 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         self.query.search = QuerySearchParams {
             search: Some(search.into()),
             search_columns: Some(search_columns.into_iter().map(Into::into).collect()),
+            search_mode: self.query.search.search_mode,
         };
         self
     }
 
+    /// Sets how the search text matches the search columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `search_mode` - Contains (default), starts with, ends with, exact or whole word
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder, QuerySearchMode};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_search("jo", vec!["name"])
+    ///     .with_search_mode(QuerySearchMode::StartsWith)
+    ///     .build();
+    ///
+    /// assert_eq!(params.search.search_mode, QuerySearchMode::StartsWith);
+    /// ```
+    pub fn with_search_mode(mut self, search_mode: QuerySearchMode) -> Self {
+        self.query.search.search_mode = search_mode;
+        self
+    }
+
     /// Sets date range parameters for filtering by date.
     ///
     /// # Arguments
@@ -187,6 +303,78 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         self
     }
 
+    /// Sets the position of the last seen row for keyset (seek) pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `after_sort_value` - Value of the sort column on the last seen row
+    /// * `after_tie_breaker_value` - Value of the tie-breaker column on the last seen row
+    ///
+    /// # Details
+    ///
+    /// Only used when keyset pagination is enabled on the `PaginatedQueryBuilder`.
+    /// Both values are required for the position to be applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     id: i64,
+    ///     created_at: String
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_sort("created_at", QuerySortDirection::Descending)
+    ///     .with_keyset("2024-11-02T12:30:12Z", "42")
+    ///     .build();
+    /// ```
+    pub fn with_keyset(
+        mut self,
+        after_sort_value: impl Into<String>,
+        after_tie_breaker_value: impl Into<String>,
+    ) -> Self {
+        self.query.keyset = QueryKeysetParams {
+            after_sort_value: Some(after_sort_value.into()),
+            after_tie_breaker_value: Some(after_tie_breaker_value.into()),
+        };
+        self
+    }
+
+    /// Sets the cursor token returned as `next_cursor` or `prev_cursor` by a previous page.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Opaque cursor token
+    ///
+    /// # Details
+    ///
+    /// Only used when keyset pagination is enabled on the `PaginatedQueryBuilder`,
+    /// where it takes precedence over the page number and the keyset values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_cursor("eyJkaXJlY3Rpb24iOiJuZXh0In0")
+    ///     .build();
+    /// ```
+    pub fn with_cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.query.cursor = Some(cursor.into());
+        self
+    }
+
     /// Adds a single filter condition.
     ///
     /// # Arguments
@@ -220,9 +408,7 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
     /// ```
     pub fn with_filter(mut self, key: impl Into<String>, value: Option<impl Into<String>>) -> Self {
         let key = key.into();
-        let valid_fields = get_struct_field_names::<T>();
-
-        if valid_fields.contains(&key) {
+        if is_valid_filter_key(&key, &self.registry) {
             self.query.filters.insert(key, value.map(Into::into));
         } else {
             #[cfg(feature = "tracing")]
@@ -231,6 +417,45 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         self
     }
 
+    /// Adds a single filter condition using a comparison operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Column name to filter on
+    /// * `operator` - Operator comparing the column with the value
+    /// * `value` - Optional value to filter by, comma separated for `In`, `NotIn` and `Between`
+    ///
+    /// # Details
+    ///
+    /// Equivalent to `with_filter("column[operator]", value)`.
+    /// Only adds the filter if the column exists in the model struct.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryFilterOperator, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct ProductExample {
+    ///     price: i64,
+    ///     status: String
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<ProductExample>::new()
+    ///     .with_filter_operator("price", QueryFilterOperator::Between, Some("10,20"))
+    ///     .with_filter_operator("status", QueryFilterOperator::NotIn, Some("deleted,archived"))
+    ///     .build();
+    /// ```
+    pub fn with_filter_operator(
+        self,
+        column: impl AsRef<str>,
+        operator: QueryFilterOperator,
+        value: Option<impl Into<String>>,
+    ) -> Self {
+        self.with_filter(get_filter_key(column.as_ref(), operator), value)
+    }
+
     /// Adds multiple filter conditions from a HashMap.
     ///
     /// # Arguments
@@ -268,13 +493,13 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         mut self,
         filters: HashMap<impl Into<String>, Option<impl Into<String>>>,
     ) -> Self {
-        let valid_fields = get_struct_field_names::<T>();
+        let registry = &self.registry;
 
         self.query
             .filters
             .extend(filters.into_iter().filter_map(|(key, value)| {
                 let key = key.into();
-                if valid_fields.contains(&key) {
+                if is_valid_filter_key(&key, registry) {
                     Some((key, value.map(Into::into)))
                 } else {
                     #[cfg(feature = "tracing")]
@@ -286,6 +511,51 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         self
     }
 
+    /// Adds a filter expression, combining conditions with `AND`, `OR` and `NOT`.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The filter expression
+    ///
+    /// # Details
+    ///
+    /// - Combined with `AND` with the filters and with the expressions added before
+    /// - Columns are validated when the query is built rather than skipped here, as leaving out
+    ///   part of the expression would change its meaning
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{FilterExpr, QueryFilterOperator, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     status: String,
+    ///     region: String
+    /// }
+    ///
+    /// // status is active or trial, and region is EU
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_filter_expr(FilterExpr::Or(vec![
+    ///         FilterExpr::leaf("status", QueryFilterOperator::Eq, Some("active")),
+    ///         FilterExpr::leaf("status", QueryFilterOperator::Eq, Some("trial")),
+    ///     ]))
+    ///     .with_filter("region", Some("EU"))
+    ///     .build();
+    /// ```
+    pub fn with_filter_expr(mut self, expr: FilterExpr) -> Self {
+        self.query.filter = Some(match self.query.filter.take() {
+            Some(FilterExpr::And(mut exprs)) => {
+                exprs.push(expr);
+                FilterExpr::And(exprs)
+            }
+            Some(filter) => FilterExpr::And(vec![filter, expr]),
+            None => expr,
+        });
+        self
+    }
+
     /// Builds and returns the final QueryParams.
     ///
     /// # Returns
@@ -521,6 +791,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_filter_expr() {
+        let status_filter = FilterExpr::Or(vec![
+            FilterExpr::leaf("status", QueryFilterOperator::Eq, Some("active")),
+            FilterExpr::leaf("status", QueryFilterOperator::Eq, Some("trial")),
+        ]);
+        let name_filter = FilterExpr::leaf("name", QueryFilterOperator::Like, Some("john"));
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter_expr(status_filter.clone())
+            .build();
+        assert_eq!(params.filter, Some(status_filter.clone()));
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter_expr(status_filter.clone())
+            .with_filter_expr(name_filter.clone())
+            .with_filter_expr(FilterExpr::leaf(
+                "category",
+                QueryFilterOperator::IsNull,
+                None::<String>,
+            ))
+            .build();
+        assert_eq!(
+            params.filter,
+            Some(FilterExpr::And(vec![
+                status_filter,
+                name_filter,
+                FilterExpr::leaf("category", QueryFilterOperator::IsNull, None::<String>),
+            ]))
+        );
+    }
+
     #[test]
     fn test_search_with_columns() {
         let params = QueryParamsBuilder::<TestModel>::new()
@@ -582,6 +884,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multiple_sorts() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sorts(vec![
+                ("status", QuerySortDirection::Ascending),
+                ("created_at", QuerySortDirection::Descending),
+            ])
+            .build();
+
+        assert_eq!(params.sort.sort_column, "status");
+        assert_eq!(params.sort.sort_direction, QuerySortDirection::Ascending);
+        assert_eq!(params.sort.get_sort_terms().len(), 2);
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sorts(vec![("status", QuerySortDirection::Ascending)])
+            .with_sort("title", QuerySortDirection::Descending)
+            .build();
+
+        assert_eq!(
+            params.sort.get_sort_terms(),
+            vec![QuerySortTerm::new("title", QuerySortDirection::Descending)]
+        );
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sorts(Vec::<(String, QuerySortDirection)>::new())
+            .build();
+
+        assert_eq!(params.sort.sort_column, DEFAULT_SORT_COLUMN_NAME);
+    }
+
+    #[test]
+    fn test_filter_operators() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter_operator(
+                "status",
+                QueryFilterOperator::NotIn,
+                Some("deleted,archived"),
+            )
+            .with_filter_operator("category", QueryFilterOperator::Eq, Some("test"))
+            .with_filter("updated_at__gte", Some("2024-01-01"))
+            .with_filter("unknown[gte]", Some("1"))
+            .with_filter("status[drop]", Some("1"))
+            .build();
+
+        assert_eq!(
+            params.filters.get("status[not_in]").unwrap(),
+            &Some("deleted,archived".to_string())
+        );
+        assert_eq!(
+            params.filters.get("category").unwrap(),
+            &Some("test".to_string())
+        );
+        assert!(params.filters.contains_key("updated_at__gte"));
+        assert_eq!(params.filters.len(), 3);
+    }
+
     #[test]
     fn test_mixed_pagination() {
         let params = QueryParamsBuilder::<TestModel>::new()
@@ -0,0 +1,451 @@
+use crate::paginated_query_as::internal::get_struct_field_names;
+use crate::{ColumnType, QuerySearchMode};
+use serde::Serialize;
+
+/// Metadata of a model field exposed to the query parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaginatedColumn {
+    /// Name of the field, as used by the query parameters.
+    pub name: String,
+    /// SQL column the field maps to, e.g. `email` or `u.email`.
+    pub column: String,
+    /// Declared SQL type, filter values are cast from their text when unknown.
+    pub column_type: Option<ColumnType>,
+    pub sortable: bool,
+    pub filterable: bool,
+    pub searchable: bool,
+    /// How the search text matches the column, the search mode of the params when unset.
+    pub search_mode: Option<QuerySearchMode>,
+    /// Weight of a search match in the column towards the relevance of a row.
+    pub search_weight: Option<u32>,
+    /// Name of the column in the rows of the query, as read by `FromRow`, the field name when unset.
+    pub output_name: Option<String>,
+}
+
+impl PaginatedColumn {
+    /// Creates a column mapped to the SQL column of the same name, usable in every clause.
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+
+        Self {
+            column: name.clone(),
+            name,
+            column_type: None,
+            sortable: true,
+            filterable: true,
+            searchable: true,
+            search_mode: None,
+            search_weight: None,
+            output_name: None,
+        }
+    }
+}
+
+/// Columns of a model the query builders accept, with what each of them can be used for.
+///
+/// The registry is either derived from the serialized field names of `T::default()`,
+/// where every field can be sorted, filtered and searched, or declared by the model
+/// through `PaginatedModel` (see `#[derive(Paginated)]` with the `derive` feature).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnRegistry {
+    columns: Vec<PaginatedColumn>,
+}
+
+impl ColumnRegistry {
+    pub fn new(columns: Vec<PaginatedColumn>) -> Self {
+        Self { columns }
+    }
+
+    /// Creates a registry from the serialized field names of `T::default()`.
+    pub fn from_struct_fields<T>() -> Self
+    where
+        T: Default + Serialize,
+    {
+        Self::new(
+            get_struct_field_names::<T>()
+                .into_iter()
+                .map(PaginatedColumn::new)
+                .collect(),
+        )
+    }
+
+    /// Declares the SQL type of a registered column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{ColumnRegistry, ColumnType};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct AddressExample {
+    ///     zip: String
+    /// }
+    ///
+    /// let registry = ColumnRegistry::from_struct_fields::<AddressExample>()
+    ///     .with_column_type("zip", ColumnType::Text);
+    /// assert_eq!(registry.column_type("zip"), Some(ColumnType::Text));
+    /// ```
+    pub fn with_column_type(mut self, name: &str, column_type: ColumnType) -> Self {
+        if let Some(column) = self.columns.iter_mut().find(|column| column.name == name) {
+            column.column_type = Some(column_type);
+        }
+        self
+    }
+
+    /// Sets how the search text matches a registered column, whatever the search mode of the params.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{ColumnRegistry, ColumnType, QuerySearchMode};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct ProductExample {
+    ///     id: i64,
+    ///     sku: String,
+    /// }
+    ///
+    /// let registry = ColumnRegistry::from_struct_fields::<ProductExample>()
+    ///     .with_column_type("id", ColumnType::BigInt)
+    ///     .with_search_mode("id", QuerySearchMode::Exact)
+    ///     .with_search_mode("sku", QuerySearchMode::StartsWith);
+    /// assert_eq!(registry.search_mode("sku"), Some(QuerySearchMode::StartsWith));
+    /// ```
+    pub fn with_search_mode(mut self, name: &str, search_mode: QuerySearchMode) -> Self {
+        if let Some(column) = self.columns.iter_mut().find(|column| column.name == name) {
+            column.search_mode = Some(search_mode);
+        }
+        self
+    }
+
+    /// Sets the weight of a search match in a registered column.
+    ///
+    /// # Details
+    ///
+    /// Once a searched column has a weight, the relevance of a row is the sum of the weights
+    /// of its matching columns, 1 for columns without one. `PaginatedQueryBuilder` orders pages
+    /// without an explicit sort by relevance, most relevant first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::ColumnRegistry;
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct ArticleExample {
+    ///     name: String,
+    ///     description: String,
+    /// }
+    ///
+    /// let registry = ColumnRegistry::from_struct_fields::<ArticleExample>()
+    ///     .with_search_weight("name", 3);
+    /// assert_eq!(registry.search_weight("name"), Some(3));
+    /// assert_eq!(registry.search_weight("description"), None);
+    /// ```
+    pub fn with_search_weight(mut self, name: &str, weight: u32) -> Self {
+        if let Some(column) = self.columns.iter_mut().find(|column| column.name == name) {
+            column.search_weight = Some(weight);
+        }
+        self
+    }
+
+    pub fn columns(&self) -> &[PaginatedColumn] {
+        &self.columns
+    }
+
+    /// Returns the column registered under the given field name.
+    pub fn get(&self, name: &str) -> Option<&PaginatedColumn> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    pub fn is_sortable(&self, name: &str) -> bool {
+        self.get(name).is_some_and(|column| column.sortable)
+    }
+
+    pub fn is_filterable(&self, name: &str) -> bool {
+        self.get(name).is_some_and(|column| column.filterable)
+    }
+
+    pub fn is_searchable(&self, name: &str) -> bool {
+        self.get(name).is_some_and(|column| column.searchable)
+    }
+
+    pub fn column_type(&self, name: &str) -> Option<ColumnType> {
+        self.get(name).and_then(|column| column.column_type)
+    }
+
+    pub fn search_mode(&self, name: &str) -> Option<QuerySearchMode> {
+        self.get(name).and_then(|column| column.search_mode)
+    }
+
+    pub fn search_weight(&self, name: &str) -> Option<u32> {
+        self.get(name).and_then(|column| column.search_weight)
+    }
+
+    /// Returns the SQL column of a field, or the name itself when it isn't registered.
+    pub fn column_of<'a>(&'a self, name: &'a str) -> &'a str {
+        self.get(name).map_or(name, |column| column.column.as_str())
+    }
+
+    /// Returns the registry with each SQL column resolved to the output name of its field,
+    /// `u.email` becoming `email`, for conditions applied over the result of a query.
+    /// Unqualified columns without an output name are kept.
+    pub(crate) fn unqualified(mut self) -> Self {
+        for column in &mut self.columns {
+            match &column.output_name {
+                Some(output_name) => column.column = output_name.clone(),
+                None if column.column.contains('.') => column.column = column.name.clone(),
+                None => {}
+            }
+        }
+        self
+    }
+}
+
+/// Models declaring their column registry, usually through `#[derive(Paginated)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::{ColumnRegistry, PaginatedColumn, PaginatedModel};
+///
+/// struct UserExample {
+///     email: String,
+/// }
+///
+/// impl PaginatedModel for UserExample {
+///     fn column_registry() -> ColumnRegistry {
+///         ColumnRegistry::new(vec![PaginatedColumn {
+///             column: "u.email".to_string(),
+///             sortable: false,
+///             ..PaginatedColumn::new("email")
+///         }])
+///     }
+/// }
+///
+/// assert!(UserExample::column_registry().is_filterable("email"));
+/// assert!(!UserExample::column_registry().is_sortable("email"));
+/// ```
+pub trait PaginatedModel {
+    fn column_registry() -> ColumnRegistry;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default, Serialize)]
+    struct TestModel {
+        name: String,
+        #[serde(rename = "emailAddress")]
+        email: String,
+    }
+
+    #[test]
+    fn test_registry_from_struct_fields() {
+        let registry = ColumnRegistry::from_struct_fields::<TestModel>();
+
+        assert_eq!(registry.columns().len(), 2);
+        assert!(registry.is_sortable("name"));
+        assert!(registry.is_searchable("emailAddress"));
+        assert!(!registry.contains("email"));
+        assert_eq!(registry.column_of("emailAddress"), "emailAddress");
+    }
+
+    #[test]
+    fn test_registry_capabilities() {
+        let registry = ColumnRegistry::new(vec![
+            PaginatedColumn {
+                column: "u.email".to_string(),
+                sortable: false,
+                ..PaginatedColumn::new("email")
+            },
+            PaginatedColumn::new("name"),
+        ]);
+
+        assert!(!registry.is_sortable("email"));
+        assert!(registry.is_filterable("email"));
+        assert!(!registry.is_filterable("password"));
+        assert_eq!(registry.column_of("email"), "u.email");
+        assert_eq!(registry.column_of("unknown"), "unknown");
+
+        let registry = registry.unqualified();
+        assert_eq!(registry.column_of("email"), "email");
+        assert_eq!(registry.column_of("name"), "name");
+        assert!(!registry.is_sortable("email"));
+
+        let registry = ColumnRegistry::new(vec![
+            PaginatedColumn {
+                column: "a.actor_id".to_string(),
+                ..PaginatedColumn::new("actor")
+            },
+            PaginatedColumn {
+                column: "u.mail".to_string(),
+                output_name: Some("email_address".to_string()),
+                ..PaginatedColumn::new("email")
+            },
+            PaginatedColumn {
+                column: "full_name".to_string(),
+                ..PaginatedColumn::new("name")
+            },
+        ])
+        .unqualified();
+        assert_eq!(registry.column_of("actor"), "actor");
+        assert_eq!(registry.column_of("email"), "email_address");
+        assert_eq!(registry.column_of("name"), "full_name");
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod derive_tests {
+    use super::*;
+    use crate::Paginated;
+
+    #[allow(dead_code)]
+    #[derive(Serialize, Paginated)]
+    struct AuditExample {
+        #[paginated(sortable, filterable)]
+        id: i64,
+        #[paginated(filterable, column = "a.actor_id")]
+        actor: String,
+        #[paginated(skip)]
+        payload: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Serialize, Paginated)]
+    struct UserExample {
+        #[serde(rename = "fullName")]
+        name: String,
+        #[paginated(searchable, column = "u.email")]
+        email: String,
+        #[paginated(flatten)]
+        audit: AuditExample,
+    }
+
+    #[test]
+    fn test_derived_registry() {
+        let registry = UserExample::column_registry();
+        let names: Vec<&str> = registry
+            .columns()
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect();
+
+        assert_eq!(names, vec!["fullName", "email", "id", "actor"]);
+        assert!(registry.is_sortable("fullName"));
+        assert!(registry.is_searchable("email"));
+        assert!(!registry.is_filterable("email"));
+        assert!(!registry.is_searchable("id"));
+        assert!(!registry.is_sortable("actor"));
+        assert!(!registry.contains("payload"));
+        assert_eq!(registry.column_of("email"), "u.email");
+        assert_eq!(registry.column_of("actor"), "a.actor_id");
+        assert_eq!(registry.column_type("id"), Some(ColumnType::BigInt));
+        assert_eq!(registry.column_type("fullName"), Some(ColumnType::Text));
+    }
+
+    #[allow(dead_code)]
+    #[derive(Serialize, sqlx::FromRow, Paginated)]
+    #[sqlx(rename_all = "camelCase")]
+    struct ReviewExample {
+        #[paginated(column = "r.id")]
+        review_id: i64,
+        #[sqlx(rename = "body_text")]
+        #[paginated(column = "r.body")]
+        body: String,
+    }
+
+    #[test]
+    fn test_derived_output_names() {
+        // The last segment of the column isn't the name of the field in the select list
+        let registry = UserExample::column_registry().unqualified();
+        assert_eq!(registry.column_of("actor"), "actor");
+        assert_eq!(registry.column_of("email"), "email");
+        assert_eq!(registry.column_of("fullName"), "name");
+
+        let registry = ReviewExample::column_registry().unqualified();
+        assert_eq!(registry.column_of("review_id"), "reviewId");
+        assert_eq!(registry.column_of("body"), "body_text");
+    }
+
+    #[allow(dead_code)]
+    #[derive(Serialize, Paginated)]
+    #[serde(rename_all = "camelCase")]
+    struct AccountExample {
+        account_id: i64,
+        #[serde(rename = "email")]
+        email_address: String,
+        #[serde(skip)]
+        password_hash: String,
+        #[serde(skip_serializing)]
+        api_token: String,
+    }
+
+    #[test]
+    fn test_derived_registry_serde_names() {
+        let registry = AccountExample::column_registry();
+        let names: Vec<&str> = registry
+            .columns()
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect();
+
+        assert_eq!(names, vec!["accountId", "email"]);
+    }
+
+    #[allow(dead_code)]
+    #[derive(Paginated)]
+    struct EventExample {
+        #[paginated(sql_type = "timestamptz")]
+        occurred_at: String,
+        deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_derived_column_types() {
+        let registry = EventExample::column_registry();
+
+        assert_eq!(
+            registry.column_type("occurred_at"),
+            Some(ColumnType::TimestampTz)
+        );
+        assert_eq!(
+            registry.column_type("deleted_at"),
+            Some(ColumnType::TimestampTz)
+        );
+        assert_eq!(registry.column_type("tags"), None);
+    }
+
+    #[allow(dead_code)]
+    #[derive(Paginated)]
+    struct ProductExample {
+        #[paginated(searchable, search_mode = "exact")]
+        id: i64,
+        #[paginated(searchable, search_mode = "starts_with", search_weight = 3)]
+        sku: String,
+        name: String,
+    }
+
+    #[test]
+    fn test_derived_search_attributes() {
+        let registry = ProductExample::column_registry();
+
+        assert_eq!(registry.search_mode("id"), Some(QuerySearchMode::Exact));
+        assert_eq!(
+            registry.search_mode("sku"),
+            Some(QuerySearchMode::StartsWith)
+        );
+        assert_eq!(registry.search_mode("name"), None);
+        assert_eq!(registry.search_weight("sku"), Some(3));
+        assert_eq!(registry.search_weight("id"), None);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::ColumnType;
+use sqlx::error::BoxDynError;
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned while building or executing a paginated query.
+#[derive(Debug)]
+pub enum PaginationError {
+    /// The column isn't a field of the model.
+    InvalidColumn(String),
+    /// The column is a field of the model but is blocked by the column protection.
+    UnsafeColumn(String),
+    /// The sort column isn't a field of the model or is blocked by the column protection.
+    InvalidSortColumn(String),
+    /// The filter or keyset value doesn't parse as the declared type of its column.
+    InvalidValue {
+        column: String,
+        value: String,
+        column_type: ColumnType,
+    },
+    /// The cursor is malformed, tampered with, or issued for other sort and filters.
+    InvalidCursor,
+    /// The page size exceeds the maximum allowed by the pagination limits.
+    InvalidPageSize { page_size: i64, max_page_size: i64 },
+    /// The filter or sort expression doesn't parse or uses an unsupported function.
+    InvalidExpression(String),
+    /// The builder is configured with columns the model doesn't have, e.g. a sort fallback,
+    /// or the query selects a column reserved by the builder.
+    InvalidConfiguration(String),
+    /// A value couldn't be bound to the query arguments.
+    BindFailure(BoxDynError),
+    /// The query failed to execute.
+    Database(sqlx::Error),
+}
+
+impl PaginationError {
+    /// Returns `true` when the error is caused by the request parameters rather than the
+    /// database, e.g. to answer with `400 Bad Request`.
+    pub fn is_client_error(&self) -> bool {
+        !matches!(
+            self,
+            PaginationError::InvalidConfiguration(_)
+                | PaginationError::BindFailure(_)
+                | PaginationError::Database(_)
+        )
+    }
+
+    /// Returns a stable, machine readable name of the error, e.g. for API error bodies.
+    pub fn code(&self) -> &'static str {
+        match self {
+            PaginationError::InvalidColumn(_) => "invalid_column",
+            PaginationError::UnsafeColumn(_) => "unsafe_column",
+            PaginationError::InvalidSortColumn(_) => "invalid_sort_column",
+            PaginationError::InvalidValue { .. } => "invalid_value",
+            PaginationError::InvalidCursor => "invalid_cursor",
+            PaginationError::InvalidPageSize { .. } => "invalid_page_size",
+            PaginationError::InvalidExpression(_) => "invalid_expression",
+            PaginationError::InvalidConfiguration(_) => "invalid_configuration",
+            PaginationError::BindFailure(_) => "bind_failure",
+            PaginationError::Database(_) => "database_error",
+        }
+    }
+}
+
+impl fmt::Display for PaginationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaginationError::InvalidColumn(column) => write!(f, "Invalid column: {}", column),
+            PaginationError::UnsafeColumn(column) => write!(f, "Unsafe column: {}", column),
+            PaginationError::InvalidSortColumn(column) => {
+                write!(f, "Invalid sort column: {}", column)
+            }
+            PaginationError::InvalidValue {
+                column,
+                value,
+                column_type,
+            } => write!(
+                f,
+                "Invalid value for column {}: {:?} is not a valid {}",
+                column, value, column_type
+            ),
+            PaginationError::InvalidCursor => write!(f, "Invalid cursor"),
+            PaginationError::InvalidPageSize {
+                page_size,
+                max_page_size,
+            } => write!(
+                f,
+                "Invalid page size: {} exceeds the maximum of {}",
+                page_size, max_page_size
+            ),
+            PaginationError::InvalidExpression(reason) => {
+                write!(f, "Invalid expression: {}", reason)
+            }
+            PaginationError::InvalidConfiguration(reason) => {
+                write!(f, "Invalid configuration: {}", reason)
+            }
+            PaginationError::BindFailure(error) => write!(f, "Failed to bind value: {}", error),
+            PaginationError::Database(error) => write!(f, "Database error: {}", error),
+        }
+    }
+}
+
+impl Error for PaginationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PaginationError::BindFailure(error) => Some(error.as_ref()),
+            PaginationError::Database(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<sqlx::Error> for PaginationError {
+    fn from(error: sqlx::Error) -> Self {
+        PaginationError::Database(error)
+    }
+}
+
+impl From<PaginationError> for sqlx::Error {
+    fn from(error: PaginationError) -> Self {
+        match error {
+            PaginationError::Database(error) => error,
+            PaginationError::BindFailure(error) => sqlx::Error::Encode(error),
+            PaginationError::InvalidConfiguration(_) => {
+                sqlx::Error::Configuration(error.to_string().into())
+            }
+            PaginationError::InvalidColumn(column)
+            | PaginationError::UnsafeColumn(column)
+            | PaginationError::InvalidSortColumn(column) => sqlx::Error::ColumnNotFound(column),
+            PaginationError::InvalidValue { .. } => sqlx::Error::Encode(error.to_string().into()),
+            PaginationError::InvalidCursor
+            | PaginationError::InvalidPageSize { .. }
+            | PaginationError::InvalidExpression(_) => {
+                sqlx::Error::Decode(error.to_string().into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pagination_error_display() {
+        assert_eq!(
+            PaginationError::InvalidSortColumn("ctid".to_string()).to_string(),
+            "Invalid sort column: ctid"
+        );
+        assert_eq!(
+            PaginationError::UnsafeColumn("pg_class".to_string()).to_string(),
+            "Unsafe column: pg_class"
+        );
+        assert_eq!(PaginationError::InvalidCursor.to_string(), "Invalid cursor");
+        assert_eq!(
+            PaginationError::InvalidValue {
+                column: "age".to_string(),
+                value: "old".to_string(),
+                column_type: ColumnType::Integer,
+            }
+            .to_string(),
+            "Invalid value for column age: \"old\" is not a valid integer"
+        );
+        assert!(PaginationError::from(sqlx::Error::RowNotFound)
+            .to_string()
+            .starts_with("Database error"));
+    }
+
+    #[test]
+    fn test_pagination_error_is_client_error() {
+        assert!(PaginationError::InvalidColumn("email".to_string()).is_client_error());
+        assert!(PaginationError::InvalidCursor.is_client_error());
+        assert!(PaginationError::InvalidPageSize {
+            page_size: 500,
+            max_page_size: 100
+        }
+        .is_client_error());
+        assert!(!PaginationError::BindFailure("overflow".into()).is_client_error());
+        assert!(!PaginationError::Database(sqlx::Error::RowNotFound).is_client_error());
+    }
+
+    #[test]
+    fn test_pagination_error_code() {
+        assert_eq!(
+            PaginationError::InvalidSortColumn("ctid".to_string()).code(),
+            "invalid_sort_column"
+        );
+        assert_eq!(
+            PaginationError::Database(sqlx::Error::RowNotFound).code(),
+            "database_error"
+        );
+    }
+
+    #[test]
+    fn test_pagination_error_into_sqlx_error() {
+        let error: sqlx::Error = PaginationError::InvalidSortColumn("ctid".to_string()).into();
+        assert!(matches!(error, sqlx::Error::ColumnNotFound(column) if column == "ctid"));
+
+        let error: sqlx::Error = PaginationError::BindFailure("overflow".into()).into();
+        assert!(matches!(error, sqlx::Error::Encode(_)));
+
+        let error: sqlx::Error = PaginationError::Database(sqlx::Error::RowNotFound).into();
+        assert!(matches!(error, sqlx::Error::RowNotFound));
+    }
+}
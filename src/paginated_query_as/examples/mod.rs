@@ -1,3 +1,4 @@
+#[cfg(feature = "postgres")]
 mod paginated_query_builder_advanced_examples;
 mod query_builder_examples;
 
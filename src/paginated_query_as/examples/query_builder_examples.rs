@@ -1,5 +1,50 @@
-use crate::{QueryBuilder, QueryParams};
+use crate::paginated_query_as::internal::QueryDatabase;
+use crate::{PaginationError, QueryBuilder, QueryParams};
+use chrono::{DateTime, Utc};
 use serde::Serialize;
+use sqlx::{Database, Encode, Type};
+
+#[allow(dead_code)]
+pub fn build_query_with_safe_defaults<'q, T, DB>(
+    params: &QueryParams<T>,
+) -> Result<(Vec<String>, DB::Arguments<'q>), PaginationError>
+where
+    T: Default + Serialize + 'q,
+    DB: QueryDatabase,
+    String: for<'a> Encode<'a, DB> + Type<DB>,
+    DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+{
+    build_query_with_defaults(QueryBuilder::<T, DB>::new(), params)
+}
+
+#[allow(dead_code)]
+pub fn build_query_with_strict_defaults<'q, T, DB>(
+    params: &QueryParams<T>,
+) -> Result<(Vec<String>, DB::Arguments<'q>), PaginationError>
+where
+    T: Default + Serialize + 'q,
+    DB: QueryDatabase,
+    String: for<'a> Encode<'a, DB> + Type<DB>,
+    DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+{
+    build_query_with_defaults(QueryBuilder::<T, DB>::new().strict(), params)
+}
+
+pub fn build_query_with_defaults<'q, T, DB>(
+    query_builder: QueryBuilder<'q, T, DB>,
+    params: &QueryParams<T>,
+) -> Result<(Vec<String>, DB::Arguments<'q>), PaginationError>
+where
+    DB: Database,
+    String: for<'a> Encode<'a, DB> + Type<DB>,
+    DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+{
+    query_builder
+        .with_search(params)
+        .with_filters(params)
+        .with_date_range(params)
+        .try_build()
+}
 
 #[cfg(feature = "postgres")]
 pub mod postgres_examples {
@@ -23,26 +68,13 @@ pub mod postgres_examples {
             .build()
     }
 
-    #[allow(dead_code)]
-    pub fn build_query_with_safe_defaults<'q, T, DB>(
-        params: &QueryParams<T>,
-    ) -> (Vec<String>, DB::Arguments<'q>)
-    where
-        T: Default + Serialize,
-        DB: Database<Arguments<'q> = PgArguments>,
-    {
-        QueryBuilder::<T, Postgres>::new()
-            .with_search(params)
-            .with_filters(params)
-            .with_date_range(params)
-            .build()
-    }
-
     #[cfg(test)]
     mod test {
         use super::*;
-        use crate::QueryParamsBuilder;
-        use chrono::{DateTime, Utc};
+        use crate::{
+            ColumnType, FilterExpr, FlatQueryParams, FullTextSearch, QueryFilterOperator,
+            QueryParamsBuilder, QuerySearchMode, TrigramSearch,
+        };
 
         #[derive(Debug, Default, Serialize)]
         struct TestModel {
@@ -61,7 +93,8 @@ pub mod postgres_examples {
                 .with_search("XXX", vec!["description"])
                 .build();
 
-            let (conditions, _) = build_query_with_safe_defaults::<TestModel, Postgres>(&params);
+            let (conditions, _) =
+                build_query_with_safe_defaults::<TestModel, Postgres>(&params).unwrap();
 
             assert!(!conditions.is_empty());
             assert!(conditions.iter().any(|c| c.contains("LOWER")));
@@ -74,9 +107,408 @@ pub mod postgres_examples {
                 .with_search("   ", vec!["name"])
                 .build();
 
-            let (conditions, _) = build_query_with_safe_defaults::<TestModel, Postgres>(&params);
+            let (conditions, _) =
+                build_query_with_safe_defaults::<TestModel, Postgres>(&params).unwrap();
             assert!(!conditions.iter().any(|c| c.contains("LIKE")));
         }
+
+        #[test]
+        fn test_filter_operators_query_generation() {
+            let flat_params: FlatQueryParams = serde_json::from_value(serde_json::json!({
+                "status[ne]": "deleted",
+                "category__in": "books, games",
+                "updated_at[is_null]": null,
+                "title[ilike]": "rust",
+                "name[between]": "a,m",
+            }))
+            .unwrap();
+            let params = QueryParams::<TestModel>::from(flat_params);
+
+            let (conditions, arguments) =
+                build_query_with_safe_defaults::<TestModel, Postgres>(&params).unwrap();
+
+            // Placeholder positions depend on the iteration order of the filters
+            let mut conditions: Vec<String> = conditions
+                .iter()
+                .map(|condition| {
+                    condition
+                        .split('$')
+                        .map(|part| part.trim_start_matches(|c: char| c.is_ascii_digit()))
+                        .collect::<Vec<_>>()
+                        .join("$")
+                })
+                .collect();
+            conditions.sort();
+
+            assert_eq!(
+                conditions,
+                vec![
+                    "\"category\" IN ($, $)",
+                    "\"name\" BETWEEN $ AND $",
+                    "\"status\" <> $",
+                    "\"updated_at\" IS NULL",
+                    "LOWER(\"title\") LIKE LOWER($) ESCAPE '!'",
+                ]
+            );
+            assert_eq!(sqlx::Arguments::len(&arguments), 6);
+        }
+
+        #[test]
+        fn test_strict_query_generation() {
+            #[derive(Debug, Default, Serialize)]
+            struct AuditModel {
+                name: String,
+                xmin_threshold: i64,
+            }
+
+            let params = QueryParamsBuilder::<AuditModel>::new()
+                .with_search("john", vec!["name", "email"])
+                .build();
+            let (conditions, _) =
+                build_query_with_safe_defaults::<AuditModel, Postgres>(&params).unwrap();
+            assert_eq!(conditions.len(), 1);
+            assert!(matches!(
+                build_query_with_strict_defaults::<AuditModel, Postgres>(&params),
+                Err(PaginationError::InvalidColumn(column)) if column == "email"
+            ));
+
+            let params = QueryParamsBuilder::<AuditModel>::new()
+                .with_filter("xmin_threshold[gt]", Some("10"))
+                .build();
+            assert!(matches!(
+                build_query_with_strict_defaults::<AuditModel, Postgres>(&params),
+                Err(PaginationError::UnsafeColumn(column)) if column == "xmin_threshold"
+            ));
+        }
+
+        #[test]
+        fn test_filter_expr_query_generation() {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_filter("category", Some("books"))
+                .build();
+            let expr = FilterExpr::Or(vec![
+                FilterExpr::Not(Box::new(FilterExpr::leaf(
+                    "status",
+                    QueryFilterOperator::In,
+                    Some("deleted,archived"),
+                ))),
+                FilterExpr::And(vec![
+                    FilterExpr::leaf("name", QueryFilterOperator::Like, Some("john")),
+                    FilterExpr::leaf("updated_at", QueryFilterOperator::IsNull, None::<String>),
+                ]),
+            ]);
+
+            let (conditions, arguments) = QueryBuilder::<TestModel, Postgres>::new()
+                .with_filters(&params)
+                .with_filter_expr(&expr)
+                .build();
+            assert_eq!(
+                conditions,
+                vec![
+                    "\"category\" = $1",
+                    "(NOT (\"status\" IN ($2, $3)) OR (\"name\" LIKE $4 ESCAPE '!' AND \"updated_at\" IS NULL))",
+                ]
+            );
+            assert_eq!(sqlx::Arguments::len(&arguments), 4);
+        }
+
+        #[test]
+        fn test_filter_expr_invalid_columns_rejected() {
+            // Dropping the leaf would turn the `or` into `status = 'active'` alone
+            let expr = FilterExpr::Or(vec![
+                FilterExpr::leaf("status", QueryFilterOperator::Eq, Some("active")),
+                FilterExpr::leaf("email", QueryFilterOperator::Eq, Some("john@example.com")),
+            ]);
+            assert!(matches!(
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_filter_expr(&expr)
+                    .try_build(),
+                Err(PaginationError::InvalidColumn(column)) if column == "email"
+            ));
+
+            let (conditions, arguments) = QueryBuilder::<TestModel, Postgres>::new()
+                .with_filter_expr(&expr)
+                .build();
+            assert_eq!(conditions, vec!["1 = 0"]);
+            assert_eq!(sqlx::Arguments::len(&arguments), 0);
+
+            let expr = FilterExpr::leaf("pg_catalog.pg_user", QueryFilterOperator::Eq, Some("1"));
+            assert!(QueryBuilder::<TestModel, Postgres>::new()
+                .with_filter_expr(&expr)
+                .try_build()
+                .is_err());
+
+            // Dropping the leaf would turn the `not` into every row
+            let expr = FilterExpr::And(vec![
+                FilterExpr::leaf("status", QueryFilterOperator::Eq, Some("active")),
+                FilterExpr::Not(Box::new(FilterExpr::leaf(
+                    "updated_at",
+                    QueryFilterOperator::Gt,
+                    Some("yesterday"),
+                ))),
+            ]);
+            let query_builder = || {
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_column_type("updated_at", ColumnType::TimestampTz)
+                    .with_filter_expr(&expr)
+            };
+            let (conditions, arguments) = query_builder().build();
+            assert_eq!(conditions, vec!["1 = 0"]);
+            assert_eq!(sqlx::Arguments::len(&arguments), 0);
+            assert!(matches!(
+                query_builder().try_build(),
+                Err(PaginationError::InvalidValue { column, .. }) if column == "updated_at"
+            ));
+        }
+
+        #[test]
+        fn test_filter_expr_limits_rejected() {
+            let leaf = FilterExpr::leaf("status", QueryFilterOperator::Eq, Some("active"));
+            let nested = (0..=32).fold(leaf.clone(), |expr, _| FilterExpr::Not(Box::new(expr)));
+            let result = QueryBuilder::<TestModel, Postgres>::new()
+                .with_filter_expr(&nested)
+                .try_build();
+            assert!(matches!(
+                result,
+                Err(PaginationError::InvalidExpression(reason)) if reason == "expression is nested too deeply"
+            ));
+
+            let wide = FilterExpr::Or(vec![leaf; 257]);
+            let (conditions, arguments) = QueryBuilder::<TestModel, Postgres>::new()
+                .with_filter_expr(&wide)
+                .build();
+            assert_eq!(conditions, vec!["1 = 0"]);
+            assert_eq!(sqlx::Arguments::len(&arguments), 0);
+            assert!(matches!(
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_filter_expr(&wide)
+                    .try_build(),
+                Err(PaginationError::InvalidExpression(reason)) if reason == "expression has more than 256 conditions"
+            ));
+        }
+
+        #[test]
+        fn test_column_type_query_generation() {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_filter("name", Some("123"))
+                .build();
+
+            let (conditions, _) =
+                build_query_with_safe_defaults::<TestModel, Postgres>(&params).unwrap();
+            assert_eq!(conditions, vec!["\"name\" = $1::smallint"]);
+
+            let (conditions, _) = build_query_with_defaults(
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_column_type("name", ColumnType::Text),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(conditions, vec!["\"name\" = $1::text"]);
+
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_filter_operator(
+                    "updated_at",
+                    QueryFilterOperator::In,
+                    Some("2024-01-01T00:00:00Z,yesterday"),
+                )
+                .build();
+            let result = build_query_with_defaults(
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_column_type("updated_at", ColumnType::TimestampTz),
+                &params,
+            );
+            assert!(matches!(
+                result,
+                Err(PaginationError::InvalidValue { column, value, column_type: ColumnType::TimestampTz })
+                    if column == "updated_at" && value == "yesterday"
+            ));
+
+            // Dropping the filter would return every row
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_filter("name", Some("john"))
+                .with_filter_operator("updated_at", QueryFilterOperator::Lt, Some("yesterday"))
+                .build();
+            let (mut conditions, arguments) = QueryBuilder::<TestModel, Postgres>::new()
+                .with_column_type("updated_at", ColumnType::TimestampTz)
+                .with_filters(&params)
+                .build();
+            conditions.sort();
+            assert_eq!(conditions, vec!["\"name\" = $1", "1 = 0"]);
+            assert_eq!(sqlx::Arguments::len(&arguments), 1);
+        }
+
+        #[test]
+        fn test_like_filter_on_typed_column_query_generation() {
+            let flat_params: FlatQueryParams = serde_json::from_value(serde_json::json!({
+                "updated_at[like]": "2024-11",
+                "name[ilike]": "john",
+            }))
+            .unwrap();
+            let params = QueryParams::<TestModel>::from(flat_params);
+
+            // Patterns match the text of columns of other types, as searches do
+            let (mut conditions, _) = build_query_with_defaults(
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_column_type("updated_at", ColumnType::TimestampTz)
+                    .with_column_type("name", ColumnType::Text),
+                &params,
+            )
+            .unwrap();
+            conditions.sort();
+            assert_eq!(conditions.len(), 2);
+            assert!(conditions[0].starts_with("CAST(\"updated_at\" AS TEXT) LIKE $"));
+            assert!(conditions[1].starts_with("LOWER(\"name\") LIKE LOWER($"));
+        }
+
+        #[test]
+        fn test_per_column_search_query_generation() {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search("2024", vec!["updated_at", "category", "title"])
+                .build();
+
+            let (conditions, arguments) = build_query_with_defaults(
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_column_type("updated_at", ColumnType::TimestampTz)
+                    .with_search_mode("category", QuerySearchMode::Exact)
+                    .with_search_mode("title", QuerySearchMode::StartsWith),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(
+                conditions,
+                vec![
+                    "(LOWER(CAST(\"updated_at\" AS TEXT)) LIKE LOWER($1) ESCAPE '!' \
+                    OR LOWER(\"category\") LIKE LOWER($2) ESCAPE '!' \
+                    OR LOWER(\"title\") LIKE LOWER($3) ESCAPE '!')"
+                ]
+            );
+            assert_eq!(sqlx::Arguments::len(&arguments), 3);
+
+            // Exact searches compare typed values, skipping columns the text isn't a value of
+            let (conditions, _) = build_query_with_defaults(
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_column_type("updated_at", ColumnType::TimestampTz)
+                    .with_search_mode("updated_at", QuerySearchMode::Exact),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(
+                conditions,
+                vec![
+                    "(LOWER(\"category\") LIKE LOWER($1) ESCAPE '!' \
+                    OR LOWER(\"title\") LIKE LOWER($2) ESCAPE '!')"
+                ]
+            );
+
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search("2024", vec!["updated_at"])
+                .build();
+            let (conditions, _) = build_query_with_defaults(
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_column_type("updated_at", ColumnType::TimestampTz)
+                    .with_search_mode("updated_at", QuerySearchMode::Exact),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(conditions, vec!["(1 = 0)"]);
+        }
+
+        #[test]
+        fn test_full_text_search_query_generation() {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search(
+                    "\"rust async\" -java",
+                    vec!["title", "email", "description"],
+                )
+                .with_filter("status", Some("active"))
+                .build();
+
+            let (conditions, _) = build_query_with_defaults(
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_full_text_search(FullTextSearch::new("english")),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(
+                conditions,
+                vec![
+                    "to_tsvector('english', coalesce(\"title\"::text, '') || ' ' || coalesce(\"description\"::text, '')) @@ websearch_to_tsquery('english', $1)",
+                    "\"status\" = $2",
+                ]
+            );
+
+            let (conditions, _) = build_query_with_defaults(
+                QueryBuilder::<TestModel, Postgres>::new().with_full_text_search(
+                    FullTextSearch::new("simple").with_vector_column("search_vector"),
+                ),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(
+                conditions[0],
+                "\"search_vector\" @@ websearch_to_tsquery('simple', $1)"
+            );
+
+            let result = QueryBuilder::<TestModel, Postgres>::new()
+                .with_full_text_search(FullTextSearch::new("english"))
+                .strict()
+                .with_search(&params)
+                .try_build();
+            assert!(matches!(
+                result,
+                Err(PaginationError::InvalidColumn(column)) if column == "email"
+            ));
+
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search("  ", vec!["title"])
+                .build();
+            let (conditions, _) = QueryBuilder::<TestModel, Postgres>::new()
+                .with_full_text_search(FullTextSearch::new("english"))
+                .with_search(&params)
+                .build();
+            assert!(conditions.is_empty());
+        }
+
+        #[test]
+        fn test_trigram_search_query_generation() {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search("jhon", vec!["name", "email", "title"])
+                .with_filter("status", Some("active"))
+                .build();
+
+            let (conditions, _) = build_query_with_defaults(
+                QueryBuilder::<TestModel, Postgres>::new()
+                    .with_trigram_search(TrigramSearch::new().with_threshold(0.45)),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(
+                conditions,
+                vec![
+                    "((\"name\"::text % $1 AND similarity(\"name\"::text, $1) >= 0.45) OR (\"title\"::text % $1 AND similarity(\"title\"::text, $1) >= 0.45))",
+                    "\"status\" = $2",
+                ]
+            );
+
+            let result = QueryBuilder::<TestModel, Postgres>::new()
+                .with_trigram_search(TrigramSearch::new())
+                .strict()
+                .with_search(&params)
+                .try_build();
+            assert!(matches!(
+                result,
+                Err(PaginationError::InvalidColumn(column)) if column == "email"
+            ));
+
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search("jhon", vec!["email"])
+                .build();
+            let (conditions, _) = QueryBuilder::<TestModel, Postgres>::new()
+                .with_trigram_search(TrigramSearch::new())
+                .with_search(&params)
+                .build();
+            assert!(conditions.is_empty());
+        }
     }
 }
 
@@ -89,7 +521,7 @@ pub mod sqlite_examples {
     #[allow(dead_code)]
     pub fn builder_new_query_with_disabled_protection_for_sqlite<'q, T, DB>(
         params: &'q QueryParams<T>,
-    ) -> (Vec<String>, DB::Arguments<'q>)
+    ) -> Result<(Vec<String>, DB::Arguments<'q>), PaginationError>
     where
         T: Default + Serialize,
         DB: Database<Arguments<'q> = SqliteArguments<'q>>,
@@ -99,14 +531,13 @@ pub mod sqlite_examples {
             .with_filters(params)
             .with_date_range(params)
             .disable_protection()
-            .build()
+            .try_build()
     }
 
     #[cfg(test)]
     mod test {
         use super::*;
         use crate::QueryParamsBuilder;
-        use chrono::{DateTime, Utc};
 
         #[derive(Debug, Default, Serialize)]
         struct TestModel {
@@ -126,8 +557,236 @@ pub mod sqlite_examples {
                 .build();
 
             let (conditions, _) =
-                builder_new_query_with_disabled_protection_for_sqlite::<TestModel, Sqlite>(&params);
+                builder_new_query_with_disabled_protection_for_sqlite::<TestModel, Sqlite>(&params)
+                    .unwrap();
             assert!(!conditions.iter().any(|c| c.contains("LIKE")));
         }
     }
 }
+
+#[cfg(feature = "mysql")]
+pub mod mysql_examples {
+    use super::*;
+    use crate::{paginated_query_as_for, PaginatedQueryBuilder, PaginatedResponse};
+    use sqlx::mysql::MySqlArguments;
+    use sqlx::{Database, FromRow, MySql, MySqlPool};
+
+    #[allow(dead_code)]
+    pub fn build_query_with_disabled_protection_for_mysql<'q, T, DB>(
+        params: &QueryParams<T>,
+    ) -> (Vec<String>, DB::Arguments<'q>)
+    where
+        T: Default + Serialize,
+        DB: Database<Arguments<'q> = MySqlArguments>,
+    {
+        QueryBuilder::<T, MySql>::new()
+            .with_search(params)
+            .with_filters(params)
+            .with_date_range(params)
+            .disable_protection()
+            .build()
+    }
+
+    #[allow(dead_code)]
+    pub async fn fetch_paginated_from_mysql<T>(
+        pool: &MySqlPool,
+        params: QueryParams<'_, T>,
+    ) -> Result<PaginatedResponse<T>, PaginationError>
+    where
+        T: for<'r> FromRow<'r, sqlx::mysql::MySqlRow> + Send + Unpin + Serialize + Default,
+    {
+        let builder: PaginatedQueryBuilder<T, MySql, _> =
+            paginated_query_as_for("SELECT * FROM users");
+
+        builder.with_params(params).fetch_paginated(pool).await
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use crate::{ColumnType, QueryParamsBuilder, QuerySearchMode};
+
+        #[derive(Debug, Default, Serialize)]
+        struct TestModel {
+            name: String,
+            status: String,
+            score: i64,
+            created_at: DateTime<Utc>,
+        }
+
+        #[test]
+        fn test_search_query_mysql() {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search("john", vec!["name", "status"])
+                .build();
+
+            let (conditions, arguments) =
+                build_query_with_safe_defaults::<TestModel, MySql>(&params).unwrap();
+            assert_eq!(
+                conditions,
+                vec!["(LOWER(`name`) LIKE LOWER(?) ESCAPE '!' OR LOWER(`status`) LIKE LOWER(?) ESCAPE '!')"]
+            );
+            assert_eq!(sqlx::Arguments::len(&arguments), 2);
+
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search("john", vec!["name"])
+                .with_search_mode(QuerySearchMode::WholeWord)
+                .build();
+            let (conditions, _) =
+                build_query_with_safe_defaults::<TestModel, MySql>(&params).unwrap();
+            assert_eq!(
+                conditions,
+                vec!["(LOWER(CONCAT(' ', `name`, ' ')) LIKE LOWER(?) ESCAPE '!')"]
+            );
+
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_search("42", vec!["score", "status"])
+                .build();
+            let (conditions, _) = build_query_with_defaults(
+                QueryBuilder::<TestModel, MySql>::new()
+                    .with_column_type("score", ColumnType::BigInt)
+                    .with_search_mode("status", QuerySearchMode::Exact),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(
+                conditions,
+                vec![
+                    "(LOWER(CAST(`score` AS CHAR)) LIKE LOWER(?) ESCAPE '!' \
+                    OR LOWER(`status`) LIKE LOWER(?) ESCAPE '!')"
+                ]
+            );
+
+            let (conditions, _) = build_query_with_defaults(
+                QueryBuilder::<TestModel, MySql>::new()
+                    .with_column_type("score", ColumnType::BigInt)
+                    .with_search_mode("score", QuerySearchMode::Exact),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(
+                conditions[0],
+                "(`score` = CAST(? AS SIGNED) OR LOWER(`status`) LIKE LOWER(?) ESCAPE '!')"
+            );
+        }
+
+        #[test]
+        fn test_filters_query_mysql() {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_filter("score", Some("42"))
+                .with_date_range(Some(Utc::now()), None, None::<String>)
+                .build();
+
+            let (conditions, arguments) =
+                build_query_with_safe_defaults::<TestModel, MySql>(&params).unwrap();
+            assert!(conditions.contains(&"`score` = CAST(? AS SIGNED)".to_string()));
+            assert!(conditions.contains(&"`created_at` >= ?".to_string()));
+            assert_eq!(sqlx::Arguments::len(&arguments), 2);
+        }
+
+        #[test]
+        fn test_column_type_query_mysql() {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_filter("status", Some("2024-01-01"))
+                .build();
+
+            let (conditions, _) = build_query_with_defaults(
+                QueryBuilder::<TestModel, MySql>::new()
+                    .with_column_type("status", ColumnType::Text),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(conditions, vec!["`status` = ?"]);
+        }
+
+        #[derive(Debug, Default, Serialize, FromRow)]
+        struct ItemModel {
+            id: i64,
+            name: String,
+            created_at: chrono::NaiveDateTime,
+        }
+
+        /// Runs against the database of `MYSQL_DATABASE_URL`, skipped when it isn't set.
+        #[tokio::test]
+        async fn test_fetch_paginated_mysql() {
+            let Ok(url) = std::env::var("MYSQL_DATABASE_URL") else {
+                return;
+            };
+            // A single connection keeps the session SQL mode for every query
+            let pool = sqlx::mysql::MySqlPoolOptions::new()
+                .max_connections(1)
+                .connect(&url)
+                .await
+                .unwrap();
+            for statement in [
+                "SET SESSION sql_mode = CONCAT(@@sql_mode, ',NO_BACKSLASH_ESCAPES')",
+                "DROP TABLE IF EXISTS sqlx_paginated_items",
+                "CREATE TABLE sqlx_paginated_items (id BIGINT PRIMARY KEY, name VARCHAR(64), created_at DATETIME)",
+                "INSERT INTO sqlx_paginated_items VALUES \
+                    (1, '50% off', '2024-01-01 10:00:00'), \
+                    (2, '500 off', '2024-01-02 10:00:00'), \
+                    (3, 'user_1', '2024-01-03 10:00:00'), \
+                    (4, 'user 1', '2024-01-04 10:00:00')",
+            ] {
+                sqlx::query(statement).execute(&pool).await.unwrap();
+            }
+
+            let fetch_ids = |params: QueryParams<'static, ItemModel>| {
+                let pool = &pool;
+                async move {
+                    paginated_query_as_for::<ItemModel, MySql>("SELECT * FROM sqlx_paginated_items")
+                        .with_params(params)
+                        .with_column_registry(
+                            crate::ColumnRegistry::from_struct_fields::<ItemModel>()
+                                .with_column_type("id", ColumnType::BigInt),
+                        )
+                        .fetch_paginated(pool)
+                        .await
+                        .unwrap()
+                        .records
+                        .iter()
+                        .map(|record| record.id)
+                        .collect::<Vec<_>>()
+                }
+            };
+
+            // Wildcards match literally whatever the backslash escaping mode
+            let search = |search: &str| {
+                QueryParamsBuilder::<ItemModel>::new()
+                    .with_search(search, vec!["name"])
+                    .build()
+            };
+            assert_eq!(fetch_ids(search("50%")).await, vec![1]);
+            assert_eq!(fetch_ids(search("user_")).await, vec![3]);
+
+            // RFC 3339 values are bound as MySQL dates and times
+            let params = QueryParamsBuilder::<ItemModel>::new()
+                .with_filter("created_at[gte]", Some("2024-01-02T11:00:00+01:00"))
+                .build();
+            assert_eq!(fetch_ids(params).await, vec![4, 3, 2]);
+
+            // Exact searches compare typed values
+            let params = QueryParamsBuilder::<ItemModel>::new()
+                .with_search("2", vec!["id", "name"])
+                .with_search_mode(QuerySearchMode::Exact)
+                .build();
+            assert_eq!(fetch_ids(params).await, vec![2]);
+
+            sqlx::query("DROP TABLE sqlx_paginated_items")
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        #[test]
+        fn test_system_schema_blocked_mysql() {
+            let params = QueryParamsBuilder::<TestModel>::new()
+                .with_filter("performance_schema.threads", Some("1"))
+                .build();
+
+            let (conditions, _) =
+                build_query_with_safe_defaults::<TestModel, MySql>(&params).unwrap();
+            assert!(conditions.is_empty());
+        }
+    }
+}
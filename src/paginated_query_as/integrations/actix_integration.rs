@@ -0,0 +1,279 @@
+use crate::paginated_query_as::integrations::PaginationRejection;
+use crate::paginated_query_as::internal::DEFAULT_PAGE;
+use crate::{FlatQueryParams, PaginatedLinks, PaginatedResponse, PaginationLimits, QueryParams};
+use actix_web::body::BoxBody;
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::web::Query;
+use actix_web::{FromRequest, HttpRequest, HttpResponse, Responder, ResponseError};
+use serde::Serialize;
+use std::future::{ready, Ready};
+
+/// Parses the query string into `QueryParams<T>`.
+///
+/// The parameters are checked against the `PaginationLimits` registered with `.app_data(limits)`
+/// on the app, scope or resource, or against the default limits when there are none.
+///
+/// # Examples
+///
+/// ```rust
+/// use actix_web::{web, App, HttpResponse};
+/// use serde::{Serialize};
+/// use sqlx_paginated::{ColumnRegistry, PaginationLimits, QueryParams};
+///
+/// #[derive(Serialize, Default)]
+/// struct UserExample {
+///     name: String
+/// }
+///
+/// async fn list_users(params: QueryParams<'static, UserExample>) -> HttpResponse {
+///     HttpResponse::Ok().body(format!("page {}", params.pagination.page))
+/// }
+///
+/// let app = App::new().service(
+///     web::resource("/users")
+///         .app_data(
+///             PaginationLimits::new()
+///                 .with_max_page_size(100)
+///                 .with_column_registry(ColumnRegistry::from_struct_fields::<UserExample>()),
+///         )
+///         .route(web::get().to(list_users)),
+/// );
+/// ```
+impl<T: 'static> FromRequest for QueryParams<'static, T> {
+    type Error = PaginationRejection;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(extract_query_params(req))
+    }
+}
+
+fn extract_query_params<T: 'static>(
+    req: &HttpRequest,
+) -> Result<QueryParams<'static, T>, PaginationRejection> {
+    let flat_params = Query::<FlatQueryParams>::from_query(req.query_string())
+        .map_err(|error| PaginationRejection::InvalidQueryString(error.to_string()))?
+        .into_inner();
+    let params = QueryParams::<T>::from(flat_params);
+
+    req.app_data::<PaginationLimits>()
+        .cloned()
+        .unwrap_or_default()
+        .validate(&params)
+        .map_err(PaginationRejection::InvalidParams)?;
+
+    Ok(params)
+}
+
+impl ResponseError for PaginationRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        HttpResponse::build(self.status_code()).json(self.to_json())
+    }
+}
+
+/// Answers with the response as JSON, along with the `X-Total-Count` header when the total is
+/// known and an RFC 8288 `Link` header pointing to the first, previous, next and last pages.
+///
+/// # Details
+///
+/// - Links are the `links` of the response when given with `PaginatedQueryBuilder::with_links`
+/// - Otherwise they keep the query string of the request and only replace `page`, or `cursor`
+///   when the response carries cursors
+/// - The `last` link is only given to offset pagination with a known total
+impl<T: Serialize> Responder for PaginatedResponse<T> {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        let mut response = HttpResponse::Ok();
+
+        if let Some(total) = self.total {
+            response.insert_header(("X-Total-Count", total.to_string()));
+        }
+        let link = match &self.links {
+            Some(links) => links.to_link_header(),
+            None => build_link_header(&self, req),
+        };
+        response.insert_header(("Link", link));
+
+        response.json(self)
+    }
+}
+
+fn build_link_header<T>(response: &PaginatedResponse<T>, req: &HttpRequest) -> String {
+    let cursor_pagination = response.next_cursor.is_some() || response.prev_cursor.is_some();
+
+    PaginatedLinks::from_response(response, cursor_pagination, |page, cursor| {
+        let param = match cursor {
+            Some(cursor) => Some(("cursor", cursor.to_string())),
+            None if page == DEFAULT_PAGE => None,
+            None => Some(("page", page.to_string())),
+        };
+        build_link_url(req, param)
+    })
+    .to_link_header()
+}
+
+/// Returns the URL of the request without its `page` and `cursor` parameters, with the given
+/// parameter appended instead.
+fn build_link_url(req: &HttpRequest, param: Option<(&str, String)>) -> String {
+    let mut url = req.full_url();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| key != "page" && key != "cursor")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+
+    if pairs.is_empty() && param.is_none() {
+        url.set_query(None);
+    } else {
+        let mut query = url.query_pairs_mut();
+        query.clear().extend_pairs(pairs);
+        if let Some((key, value)) = param {
+            query.append_pair(key, &value);
+        }
+    }
+
+    url.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paginated_query_as::internal::QueryPaginationParams;
+    use crate::ColumnRegistry;
+    use actix_web::test::TestRequest;
+
+    #[derive(Default, Serialize)]
+    struct TestModel {
+        name: String,
+        status: String,
+    }
+
+    fn test_limits() -> PaginationLimits {
+        PaginationLimits::new()
+            .with_max_page_size(50)
+            .with_column_registry(ColumnRegistry::from_struct_fields::<TestModel>())
+    }
+
+    fn test_response(page: i64, total: i64) -> PaginatedResponse<TestModel> {
+        PaginatedResponse {
+            records: vec![TestModel::default()],
+            pagination: Some(QueryPaginationParams {
+                page,
+                page_size: 10,
+            }),
+            total: Some(total),
+            total_pages: Some((total + 9) / 10),
+            total_exact: Some(true),
+            has_next_page: page * 10 < total,
+            has_previous_page: page > 1,
+            next_cursor: None,
+            prev_cursor: None,
+            links: None,
+        }
+    }
+
+    #[test]
+    fn test_extract_query_params() {
+        let req = TestRequest::with_uri("/users?page=2&page_size=30&sort=-name&status=active")
+            .app_data(test_limits())
+            .to_http_request();
+        let params = extract_query_params::<TestModel>(&req).unwrap();
+
+        assert_eq!(params.pagination.page, 2);
+        assert_eq!(params.pagination.page_size, 30);
+        assert_eq!(params.sort.get_sort_terms()[0].column, "name");
+        assert_eq!(
+            params.filters.get("status"),
+            Some(&Some("active".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_rejection() {
+        let req = TestRequest::with_uri("/users?page_size=500")
+            .app_data(test_limits())
+            .to_http_request();
+        let rejection = extract_query_params::<TestModel>(&req).err().unwrap();
+        assert_eq!(rejection.code(), "invalid_page_size");
+
+        let response = rejection.error_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = actix_web::body::to_bytes(response.into_body())
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "error": "invalid_page_size",
+                "message": "Invalid page size: 500 exceeds the maximum of 50",
+            })
+        );
+    }
+
+    #[test]
+    fn test_responder_headers() {
+        let req = TestRequest::with_uri("/users?page=2&status=active")
+            .insert_header(("Host", "example.com"))
+            .to_http_request();
+        let response = test_response(2, 45).respond_to(&req);
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get("X-Total-Count").unwrap(), "45");
+        assert_eq!(
+            response.headers().get("Link").unwrap(),
+            "<http://example.com/users?status=active>; rel=\"first\", \
+             <http://example.com/users?status=active>; rel=\"prev\", \
+             <http://example.com/users?status=active&page=3>; rel=\"next\", \
+             <http://example.com/users?status=active&page=5>; rel=\"last\""
+        );
+    }
+
+    #[test]
+    fn test_responder_response_links() {
+        let req = TestRequest::with_uri("/users?page=2").to_http_request();
+        let response = PaginatedResponse {
+            links: Some(PaginatedLinks {
+                first: "https://api.example.com/users".to_string(),
+                prev: None,
+                next: None,
+                last: None,
+            }),
+            ..test_response(1, 5)
+        }
+        .respond_to(&req);
+
+        assert_eq!(
+            response.headers().get("Link").unwrap(),
+            "<https://api.example.com/users>; rel=\"first\""
+        );
+    }
+
+    #[test]
+    fn test_responder_cursor_links() {
+        let req = TestRequest::with_uri("/users?cursor=abc&page_size=10")
+            .insert_header(("Host", "example.com"))
+            .to_http_request();
+        let response = PaginatedResponse {
+            total: None,
+            total_pages: None,
+            next_cursor: Some("def".to_string()),
+            ..test_response(1, 0)
+        }
+        .respond_to(&req);
+
+        assert!(response.headers().get("X-Total-Count").is_none());
+        assert_eq!(
+            response.headers().get("Link").unwrap(),
+            "<http://example.com/users?page_size=10>; rel=\"first\", \
+             <http://example.com/users?page_size=10&cursor=def>; rel=\"next\""
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::paginated_query_as::integrations::PaginationRejection;
+use crate::{FlatQueryParams, PaginationLimits, QueryParams};
+use axum::extract::{FromRequestParts, Query};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use std::ops::Deref;
+
+/// Axum extractor parsing the query string into `QueryParams<T>`.
+///
+/// The parameters are checked against the `PaginationLimits` of the route, declared with
+/// `.layer(Extension(limits))`, or against the default limits when the route has none.
+///
+/// # Examples
+///
+/// ```rust
+/// use axum::{routing::get, Extension, Router};
+/// use serde::{Serialize};
+/// use sqlx_paginated::{ColumnRegistry, PaginatedQuery, PaginationLimits};
+///
+/// #[derive(Serialize, Default)]
+/// struct UserExample {
+///     name: String
+/// }
+///
+/// async fn list_users(PaginatedQuery(params): PaginatedQuery<UserExample>) -> String {
+///     format!("page {}", params.pagination.page)
+/// }
+///
+/// let app: Router = Router::new().route("/users", get(list_users)).layer(Extension(
+///     PaginationLimits::new()
+///         .with_max_page_size(100)
+///         .with_column_registry(ColumnRegistry::from_struct_fields::<UserExample>()),
+/// ));
+/// ```
+pub struct PaginatedQuery<T: 'static>(pub QueryParams<'static, T>);
+
+impl<T: 'static> PaginatedQuery<T> {
+    pub fn into_inner(self) -> QueryParams<'static, T> {
+        self.0
+    }
+}
+
+impl<T: 'static> Deref for PaginatedQuery<T> {
+    type Target = QueryParams<'static, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: 'static> From<PaginatedQuery<T>> for QueryParams<'static, T> {
+    fn from(paginated: PaginatedQuery<T>) -> Self {
+        paginated.0
+    }
+}
+
+impl<T, S> FromRequestParts<S> for PaginatedQuery<T>
+where
+    T: Send + Sync + 'static,
+    S: Send + Sync,
+{
+    type Rejection = PaginationRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Query(flat_params) = Query::<FlatQueryParams>::try_from_uri(&parts.uri)
+            .map_err(|rejection| PaginationRejection::InvalidQueryString(rejection.body_text()))?;
+        let params = QueryParams::<T>::from(flat_params);
+
+        parts
+            .extensions
+            .get::<PaginationLimits>()
+            .cloned()
+            .unwrap_or_default()
+            .validate(&params)
+            .map_err(PaginationRejection::InvalidParams)?;
+
+        Ok(PaginatedQuery(params))
+    }
+}
+
+impl IntoResponse for PaginationRejection {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, Json(self.to_json())).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColumnRegistry;
+    use axum::http::Request;
+    use serde::Serialize;
+
+    #[derive(Default, Serialize)]
+    struct TestModel {
+        name: String,
+        status: String,
+    }
+
+    async fn extract(
+        uri: &str,
+        limits: Option<PaginationLimits>,
+    ) -> Result<PaginatedQuery<TestModel>, PaginationRejection> {
+        let (mut parts, _) = Request::builder().uri(uri).body(()).unwrap().into_parts();
+        if let Some(limits) = limits {
+            parts.extensions.insert(limits);
+        }
+
+        PaginatedQuery::<TestModel>::from_request_parts(&mut parts, &()).await
+    }
+
+    fn test_limits() -> PaginationLimits {
+        PaginationLimits::new()
+            .with_max_page_size(50)
+            .with_column_registry(ColumnRegistry::from_struct_fields::<TestModel>())
+    }
+
+    #[tokio::test]
+    async fn test_extract_query_params() {
+        let PaginatedQuery(params) =
+            extract("/users?page=2&page_size=30&sort=-name&status=active", None)
+                .await
+                .unwrap();
+
+        assert_eq!(params.pagination.page, 2);
+        assert_eq!(params.pagination.page_size, 30);
+        assert_eq!(params.sort.get_sort_terms()[0].column, "name");
+        assert_eq!(
+            params.filters.get("status"),
+            Some(&Some("active".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_applies_route_limits() {
+        assert!(
+            extract("/users?page_size=30&sort=name", Some(test_limits()))
+                .await
+                .is_ok()
+        );
+
+        let rejection = extract("/users?page_size=500", Some(test_limits()))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(rejection.code(), "invalid_page_size");
+
+        let rejection = extract("/users?password=secret", Some(test_limits()))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(rejection.code(), "invalid_column");
+    }
+
+    #[tokio::test]
+    async fn test_rejection_response() {
+        let rejection = extract("/users?sort=password", Some(test_limits()))
+            .await
+            .err()
+            .unwrap();
+        let response = rejection.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "error": "invalid_sort_column",
+                "message": "Invalid sort column: password",
+            })
+        );
+    }
+}
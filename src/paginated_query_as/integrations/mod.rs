@@ -0,0 +1,11 @@
+#[cfg(feature = "actix")]
+mod actix_integration;
+#[cfg(feature = "axum")]
+mod axum_integration;
+#[cfg(any(feature = "axum", feature = "actix"))]
+mod pagination_rejection;
+
+#[cfg(feature = "axum")]
+pub use axum_integration::*;
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub use pagination_rejection::*;
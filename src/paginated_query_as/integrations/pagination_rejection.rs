@@ -0,0 +1,41 @@
+use crate::PaginationError;
+use std::fmt;
+
+/// Rejection of the query parameters extractors, answered with `400 Bad Request` and a JSON
+/// body such as `{"error": "invalid_sort_column", "message": "Invalid sort column: password"}`.
+#[derive(Debug)]
+pub enum PaginationRejection {
+    /// The query string doesn't deserialize into the query parameters.
+    InvalidQueryString(String),
+    /// The query parameters exceed the limits of the route.
+    InvalidParams(PaginationError),
+}
+
+impl PaginationRejection {
+    /// Returns a stable, machine readable name of the rejection.
+    pub fn code(&self) -> &'static str {
+        match self {
+            PaginationRejection::InvalidQueryString(_) => "invalid_query_string",
+            PaginationRejection::InvalidParams(error) => error.code(),
+        }
+    }
+
+    /// Returns the JSON body of the rejection response.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "error": self.code(),
+            "message": self.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for PaginationRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaginationRejection::InvalidQueryString(message) => write!(f, "{}", message),
+            PaginationRejection::InvalidParams(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for PaginationRejection {}
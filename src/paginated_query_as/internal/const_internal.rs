@@ -1,3 +1,4 @@
+#[cfg(feature = "postgres")]
 pub static DEFAULT_EMPTY_VALUE: &str = "";
 pub static DEFAULT_SEPARATOR: &str = " ";
 pub static DEFAULT_MAX_PAGE_SIZE: i64 = 10000;
@@ -7,4 +8,13 @@ pub static DEFAULT_MAX_FIELD_LENGTH: i64 = 100;
 pub static DEFAULT_SEARCH_COLUMN_NAMES: [&str; 2] = ["name", "description"];
 pub static DEFAULT_SEARCH_COLUMN_NAME_SEPARATOR_SYMBOL: &str = ",";
 pub static DEFAULT_SORT_COLUMN_NAME: &str = "created_at";
+pub static DEFAULT_SORT_COLUMN_NAME_SEPARATOR_SYMBOL: &str = ",";
+pub static DEFAULT_SORT_DESCENDING_PREFIX_SYMBOL: &str = "-";
 pub static DEFAULT_DATE_RANGE_COLUMN_NAME: &str = "created_at";
+pub static DEFAULT_CURSOR_SIGNATURE_SEPARATOR: &str = ".";
+pub static DEFAULT_FILTER_VALUE_SEPARATOR_SYMBOL: &str = ",";
+pub static DEFAULT_LIKE_ESCAPE_SYMBOL: char = '!';
+pub static DEFAULT_SEARCH_WEIGHT: u32 = 1;
+pub static DEFAULT_MAX_FILTER_EXPRESSION_DEPTH: usize = 32;
+pub static DEFAULT_MAX_FILTER_EXPRESSION_LEAVES: usize = 256;
+pub static WINDOW_TOTAL_COLUMN_NAME: &str = "__total";
@@ -0,0 +1,3 @@
+mod query_cursor;
+
+pub use query_cursor::*;
@@ -0,0 +1,241 @@
+use crate::paginated_query_as::internal::DEFAULT_CURSOR_SIGNATURE_SEPARATOR;
+use crate::{QueryParams, QuerySearchMode, QuerySortDirection};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryCursorDirection {
+    Next,
+    Previous,
+}
+
+/// Position of a row in a keyset paginated listing, exchanged with clients as an opaque token.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QueryCursor {
+    pub direction: QueryCursorDirection,
+    pub sort_column: String,
+    pub sort_direction: QuerySortDirection,
+    pub sort_value: String,
+    pub tie_breaker_value: String,
+    pub filters_hash: String,
+}
+
+impl QueryCursor {
+    pub fn new<T>(
+        params: &QueryParams<T>,
+        direction: QueryCursorDirection,
+        sort_value: impl Into<String>,
+        tie_breaker_value: impl Into<String>,
+    ) -> Self {
+        Self {
+            direction,
+            sort_column: params.sort.sort_column.clone(),
+            sort_direction: params.sort.sort_direction.clone(),
+            sort_value: sort_value.into(),
+            tie_breaker_value: tie_breaker_value.into(),
+            filters_hash: get_filters_hash(params),
+        }
+    }
+
+    /// Encodes the cursor as URL safe base64, followed by an HMAC-SHA256 signature when a key is given.
+    pub fn encode(&self, signing_key: Option<&[u8]>) -> String {
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default());
+
+        match signing_key.and_then(|key| HmacSha256::new_from_slice(key).ok()) {
+            Some(mut mac) => {
+                mac.update(payload.as_bytes());
+                let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+                format!(
+                    "{}{}{}",
+                    payload, DEFAULT_CURSOR_SIGNATURE_SEPARATOR, signature
+                )
+            }
+            None => payload,
+        }
+    }
+
+    /// Decodes a cursor token, verifying its signature when a key is given.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if the token is malformed, unsigned while a key is expected,
+    /// or its signature doesn't match.
+    pub fn decode(token: &str, signing_key: Option<&[u8]>) -> Option<Self> {
+        let (payload, signature) = match token.split_once(DEFAULT_CURSOR_SIGNATURE_SEPARATOR) {
+            Some((payload, signature)) => (payload, Some(signature)),
+            None => (token, None),
+        };
+
+        if let Some(key) = signing_key {
+            let signature = URL_SAFE_NO_PAD.decode(signature?).ok()?;
+            let mut mac = HmacSha256::new_from_slice(key).ok()?;
+            mac.update(payload.as_bytes());
+            mac.verify_slice(&signature).ok()?;
+        }
+
+        let bytes = URL_SAFE_NO_PAD.decode(payload).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    /// Checks that the cursor was issued for the same sort and filters as the given params.
+    pub fn is_valid_for<T>(&self, params: &QueryParams<T>) -> bool {
+        self.sort_column == params.sort.sort_column
+            && self.sort_direction == params.sort.sort_direction
+            && self.filters_hash == get_filters_hash(params)
+    }
+}
+
+/// Hashes the search, date range, filters and filter expression of the params in a key order
+/// independent way.
+pub fn get_filters_hash<T>(params: &QueryParams<T>) -> String {
+    let filters: BTreeMap<&String, &Option<String>> = params.filters.iter().collect();
+    let mut canonical = json!({
+        "search": params.search.search,
+        "search_columns": params.search.search_columns,
+        "date_column": params.date_range.date_column,
+        "date_after": params.date_range.date_after,
+        "date_before": params.date_range.date_before,
+        "filters": filters,
+    });
+    // Only added when set, so that cursors issued without them stay valid
+    if let Some(filter) = &params.filter {
+        canonical["filter"] = json!(filter);
+    }
+    if params.search.search_mode != QuerySearchMode::default() {
+        canonical["search_mode"] = json!(params.search.search_mode);
+    }
+    let digest = Sha256::digest(canonical.to_string().as_bytes());
+
+    URL_SAFE_NO_PAD.encode(&digest[..16])
+}
+
+/// Converts a serialized field of a record into a value usable as a keyset position.
+pub fn get_cursor_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        Value::Bool(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FilterExpr, QueryFilterOperator, QueryParamsBuilder};
+
+    #[derive(Debug, Default, Serialize)]
+    struct TestModel {
+        id: i64,
+        status: String,
+        created_at: String,
+    }
+
+    fn test_cursor() -> QueryCursor {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter("status", Some("active"))
+            .build();
+
+        QueryCursor::new(
+            &params,
+            QueryCursorDirection::Next,
+            "2024-11-02T12:30:12Z",
+            "42",
+        )
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = test_cursor();
+
+        let unsigned = cursor.encode(None);
+        assert!(!unsigned.contains(DEFAULT_CURSOR_SIGNATURE_SEPARATOR));
+        assert_eq!(QueryCursor::decode(&unsigned, None), Some(cursor.clone()));
+
+        let signed = cursor.encode(Some(b"secret"));
+        assert_eq!(QueryCursor::decode(&signed, Some(b"secret")), Some(cursor));
+    }
+
+    #[test]
+    fn test_cursor_signature_verification() {
+        let cursor = test_cursor();
+        let signed = cursor.encode(Some(b"secret"));
+
+        // Wrong key
+        assert_eq!(QueryCursor::decode(&signed, Some(b"other")), None);
+
+        // Unsigned token while a key is expected
+        assert_eq!(
+            QueryCursor::decode(&cursor.encode(None), Some(b"secret")),
+            None
+        );
+
+        // Forged payload under a valid signature
+        let (_, signature) = signed
+            .split_once(DEFAULT_CURSOR_SIGNATURE_SEPARATOR)
+            .unwrap();
+        let mut forged = cursor.clone();
+        forged.tie_breaker_value = "1".to_string();
+        let forged_token = format!(
+            "{}{}{}",
+            forged.encode(None),
+            DEFAULT_CURSOR_SIGNATURE_SEPARATOR,
+            signature
+        );
+        assert_eq!(QueryCursor::decode(&forged_token, Some(b"secret")), None);
+    }
+
+    #[test]
+    fn test_cursor_malformed() {
+        assert_eq!(QueryCursor::decode("", None), None);
+        assert_eq!(QueryCursor::decode("not base64!", None), None);
+        assert_eq!(
+            QueryCursor::decode(&URL_SAFE_NO_PAD.encode("{}"), None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cursor_is_valid_for() {
+        let cursor = test_cursor();
+
+        let same = QueryParamsBuilder::<TestModel>::new()
+            .with_filter("status", Some("active"))
+            .build();
+        assert!(cursor.is_valid_for(&same));
+
+        let swapped_filter = QueryParamsBuilder::<TestModel>::new()
+            .with_filter("status", Some("deleted"))
+            .build();
+        assert!(!cursor.is_valid_for(&swapped_filter));
+
+        let other_sort = QueryParamsBuilder::<TestModel>::new()
+            .with_filter("status", Some("active"))
+            .with_sort("id", QuerySortDirection::Descending)
+            .build();
+        assert!(!cursor.is_valid_for(&other_sort));
+
+        let with_filter_expr = QueryParamsBuilder::<TestModel>::new()
+            .with_filter("status", Some("active"))
+            .with_filter_expr(FilterExpr::leaf("id", QueryFilterOperator::Gt, Some("10")))
+            .build();
+        assert!(!cursor.is_valid_for(&with_filter_expr));
+    }
+
+    #[test]
+    fn test_get_cursor_value() {
+        assert_eq!(get_cursor_value(&json!("abc")), Some("abc".to_string()));
+        assert_eq!(get_cursor_value(&json!(42)), Some("42".to_string()));
+        assert_eq!(get_cursor_value(&json!(true)), Some("true".to_string()));
+        assert_eq!(get_cursor_value(&Value::Null), None);
+        assert_eq!(get_cursor_value(&json!({"a": 1})), None);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::paginated_query_as::internal::DEFAULT_FILTER_VALUE_SEPARATOR_SYMBOL;
+use crate::FilterExpr;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+/// Deserializes a filter expression given as a JSON object, or as a string holding one as in
+/// query strings, failing on expressions over the depth and leaf count limits.
+pub fn filter_expr_deserialize<'de, D>(deserializer: D) -> Result<Option<FilterExpr>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let expr: FilterExpr = match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => return Ok(None),
+        Some(Value::String(value)) if value.trim().is_empty() => return Ok(None),
+        Some(Value::String(value)) => serde_json::from_str(&value).map_err(D::Error::custom)?,
+        Some(value) => serde_json::from_value(value).map_err(D::Error::custom)?,
+    };
+    expr.check_limits().map_err(D::Error::custom)?;
+
+    Ok(Some(expr))
+}
+
+/// Deserializes the value of a filter expression leaf, taking numbers and booleans as their
+/// text, and arrays as comma separated values for `in`, `not_in` and `between`.
+pub fn filter_value_deserialize<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    fn scalar_to_string<E: Error>(value: Value) -> Result<String, E> {
+        match value {
+            Value::String(value) => Ok(value),
+            Value::Number(value) => Ok(value.to_string()),
+            Value::Bool(value) => Ok(value.to_string()),
+            value => Err(E::custom(format!("invalid filter value: {}", value))),
+        }
+    }
+
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Array(values)) => Ok(Some(
+            values
+                .into_iter()
+                .map(scalar_to_string)
+                .collect::<Result<Vec<String>, D::Error>>()?
+                .join(DEFAULT_FILTER_VALUE_SEPARATOR_SYMBOL),
+        )),
+        Some(value) => scalar_to_string(value).map(Some),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paginated_query_as::internal::{
+        DEFAULT_MAX_FILTER_EXPRESSION_DEPTH, DEFAULT_MAX_FILTER_EXPRESSION_LEAVES,
+    };
+    use crate::QueryFilterOperator;
+
+    #[test]
+    fn test_filter_expr_deserialize() {
+        let expected = FilterExpr::Or(vec![
+            FilterExpr::leaf("status", QueryFilterOperator::In, Some("active,trial")),
+            FilterExpr::Not(Box::new(FilterExpr::leaf(
+                "age",
+                QueryFilterOperator::Gte,
+                Some("18"),
+            ))),
+            FilterExpr::leaf("region", QueryFilterOperator::Eq, Some("EU")),
+        ]);
+        let json = serde_json::json!({
+            "or": [
+                { "column": "status", "operator": "in", "value": ["active", "trial"] },
+                { "not": { "column": "age", "operator": "gte", "value": 18 } },
+                { "column": "region", "value": "EU" },
+            ]
+        });
+
+        assert_eq!(
+            filter_expr_deserialize(json.clone()).unwrap(),
+            Some(expected.clone())
+        );
+        assert_eq!(
+            filter_expr_deserialize(Value::String(json.to_string())).unwrap(),
+            Some(expected)
+        );
+        assert_eq!(filter_expr_deserialize(Value::Null).unwrap(), None);
+        assert!(filter_expr_deserialize(serde_json::json!({ "xor": [] })).is_err());
+        assert!(filter_expr_deserialize(serde_json::json!({
+            "column": "tags",
+            "value": [["nested"]],
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_filter_expr_deserialize_limits() {
+        let leaf = serde_json::json!({ "column": "status", "value": "active" });
+        let nested = (0..=DEFAULT_MAX_FILTER_EXPRESSION_DEPTH)
+            .fold(leaf.clone(), |expr, _| serde_json::json!({ "not": expr }));
+        assert_eq!(
+            filter_expr_deserialize(nested).unwrap_err().to_string(),
+            "Invalid expression: expression is nested too deeply"
+        );
+
+        let wide = serde_json::json!({
+            "or": vec![leaf.clone(); DEFAULT_MAX_FILTER_EXPRESSION_LEAVES + 1]
+        });
+        assert_eq!(
+            filter_expr_deserialize(wide).unwrap_err().to_string(),
+            "Invalid expression: expression has more than 256 conditions"
+        );
+
+        let widest = serde_json::json!({
+            "or": vec![leaf; DEFAULT_MAX_FILTER_EXPRESSION_LEAVES]
+        });
+        assert!(filter_expr_deserialize(widest).is_ok());
+    }
+}
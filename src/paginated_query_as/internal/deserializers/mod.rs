@@ -1,9 +1,13 @@
+mod filter_expr_deserialize;
 mod page_deserialize;
 mod page_size_deserialize;
 mod search_columns_deserialize;
 mod search_deserialize;
+mod sorts_deserialize;
 
+pub use filter_expr_deserialize::*;
 pub use page_deserialize::*;
 pub use page_size_deserialize::*;
 pub use search_columns_deserialize::*;
 pub use search_deserialize::*;
+pub use sorts_deserialize::*;
@@ -59,7 +59,11 @@ mod tests {
             DEFAULT_MIN_PAGE_SIZE
         );
         assert_eq!(
-            deserialize_test(r#""100""#, page_size_deserialize).unwrap(),
+            deserialize_test(
+                &format!(r#""{}""#, DEFAULT_MAX_PAGE_SIZE + 100),
+                page_size_deserialize
+            )
+            .unwrap(),
             DEFAULT_MAX_PAGE_SIZE
         );
 
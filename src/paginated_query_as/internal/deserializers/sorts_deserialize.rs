@@ -0,0 +1,85 @@
+use crate::paginated_query_as::internal::{
+    QuerySortTerm, DEFAULT_SORT_COLUMN_NAME_SEPARATOR_SYMBOL, DEFAULT_SORT_DESCENDING_PREFIX_SYMBOL,
+};
+use crate::QuerySortDirection;
+use serde::{Deserialize, Deserializer};
+
+pub fn sorts_deserialize<'de, D>(deserializer: D) -> Result<Vec<QuerySortTerm>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+
+    Ok(value
+        .map(|s| {
+            s.split(DEFAULT_SORT_COLUMN_NAME_SEPARATOR_SYMBOL)
+                .filter_map(|s| {
+                    let trimmed = s.trim();
+                    let (column, direction) =
+                        match trimmed.strip_prefix(DEFAULT_SORT_DESCENDING_PREFIX_SYMBOL) {
+                            Some(column) => (column, QuerySortDirection::Descending),
+                            None => (
+                                trimmed.trim_start_matches('+'),
+                                QuerySortDirection::Ascending,
+                            ),
+                        };
+                    let column = column.trim();
+
+                    if column.is_empty() {
+                        None
+                    } else {
+                        Some(QuerySortTerm::new(column, direction))
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    fn deserialize_test<T, F>(json: &str, deserialize_fn: F) -> Result<T, serde_json::Error>
+    where
+        F: FnOnce(Value) -> Result<T, serde_json::Error>,
+    {
+        let value: Value = serde_json::from_str(json)?;
+        deserialize_fn(value)
+    }
+
+    #[test]
+    fn test_sorts_deserialize() {
+        // Default cases
+        assert_eq!(
+            deserialize_test(r#"null"#, sorts_deserialize).unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            deserialize_test(r#""""#, sorts_deserialize).unwrap(),
+            vec![]
+        );
+
+        // Valid cases
+        assert_eq!(
+            deserialize_test(r#""-created_at,name""#, sorts_deserialize).unwrap(),
+            vec![
+                QuerySortTerm::new("created_at", QuerySortDirection::Descending),
+                QuerySortTerm::new("name", QuerySortDirection::Ascending),
+            ]
+        );
+        assert_eq!(
+            deserialize_test(r#""+name""#, sorts_deserialize).unwrap(),
+            vec![QuerySortTerm::new("name", QuerySortDirection::Ascending)]
+        );
+
+        // Whitespace and empty segments
+        assert_eq!(
+            deserialize_test(r#"" - created_at , ,name,-""#, sorts_deserialize).unwrap(),
+            vec![
+                QuerySortTerm::new("created_at", QuerySortDirection::Descending),
+                QuerySortTerm::new("name", QuerySortDirection::Ascending),
+            ]
+        );
+    }
+}
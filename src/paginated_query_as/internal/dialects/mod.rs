@@ -1,3 +1,8 @@
+#[cfg(feature = "mysql")]
+mod mysql_dialect;
+
+#[cfg(feature = "mysql")]
+mod mysql_dialect_utils;
 #[cfg(feature = "postgres")]
 mod postgres_dialect;
 
@@ -7,11 +12,20 @@ pub mod query_dialect;
 #[cfg(feature = "sqlite")]
 mod sqlite_dialect;
 
+#[allow(unused_imports)]
+#[cfg(feature = "mysql")]
+pub use mysql_dialect::*;
+
+#[cfg(feature = "mysql")]
+pub use mysql_dialect_utils::*;
+
+#[allow(unused_imports)]
 #[cfg(feature = "postgres")]
 pub use postgres_dialect::*;
 
 #[cfg(feature = "postgres")]
 pub use postgres_dialect_utils::*;
 
+#[allow(unused_imports)]
 #[cfg(feature = "sqlite")]
 pub use sqlite_dialect::*;
@@ -0,0 +1,143 @@
+use crate::paginated_query_as::internal::{
+    get_mysql_column_type_casting, get_mysql_date_time_value, get_mysql_type_casting,
+    quote_mysql_identifier, ColumnProtection, QueryDatabase, QueryDialect,
+    COLUMN_PROTECTION_BLOCKED_MYSQL,
+};
+use crate::ColumnType;
+
+pub struct MySqlDialect;
+
+impl QueryDialect for MySqlDialect {
+    fn quote_identifier(&self, ident: &str) -> String {
+        quote_mysql_identifier(ident)
+    }
+
+    fn placeholder(&self, _position: usize) -> String {
+        "?".to_string()
+    }
+
+    fn type_cast(&self, _value: &str) -> String {
+        String::new()
+    }
+
+    fn typed_placeholder(&self, position: usize, value: &str) -> String {
+        match get_mysql_type_casting(value) {
+            Some(cast_type) => format!("CAST({} AS {})", self.placeholder(position), cast_type),
+            None => self.placeholder(position),
+        }
+    }
+
+    fn typed_value(&self, value: &str) -> String {
+        match get_mysql_type_casting(value) {
+            Some("DATETIME") => get_mysql_date_time_value(value),
+            _ => value.to_string(),
+        }
+    }
+
+    fn typed_column_value(&self, value: String, column_type: ColumnType) -> String {
+        match column_type {
+            ColumnType::Timestamp | ColumnType::TimestampTz => get_mysql_date_time_value(&value),
+            _ => value,
+        }
+    }
+
+    fn typed_column_placeholder(&self, position: usize, column_type: ColumnType) -> String {
+        match get_mysql_column_type_casting(column_type) {
+            Some(cast_type) => format!("CAST({} AS {})", self.placeholder(position), cast_type),
+            None => self.placeholder(position),
+        }
+    }
+
+    fn text_cast(&self, expression: &str) -> String {
+        format!("CAST({} AS CHAR)", expression)
+    }
+
+    // `||` is a logical OR unless the PIPES_AS_CONCAT mode is set
+    fn concat(&self, expressions: &[&str]) -> String {
+        format!("CONCAT({})", expressions.join(", "))
+    }
+}
+
+impl QueryDatabase for sqlx::MySql {
+    fn dialect() -> Box<dyn QueryDialect> {
+        Box::new(MySqlDialect)
+    }
+
+    fn column_protection() -> ColumnProtection {
+        let mut protection = ColumnProtection::new();
+        for pattern in COLUMN_PROTECTION_BLOCKED_MYSQL {
+            protection.block_pattern(pattern);
+        }
+        protection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mysql_placeholders() {
+        let dialect = MySqlDialect;
+
+        assert_eq!(dialect.placeholder(1), "?");
+        assert_eq!(dialect.placeholder(7), "?");
+        assert_eq!(dialect.typed_placeholder(1, "42"), "CAST(? AS SIGNED)");
+        assert_eq!(
+            dialect.typed_placeholder(2, "2024-01-01 12:34:56"),
+            "CAST(? AS DATETIME)"
+        );
+        assert_eq!(dialect.typed_placeholder(3, "john"), "?");
+    }
+
+    #[test]
+    fn test_mysql_typed_values() {
+        let dialect = MySqlDialect;
+
+        assert_eq!(
+            dialect.typed_value("2024-01-01T12:34:56+02:00"),
+            "2024-01-01 10:34:56"
+        );
+        assert_eq!(dialect.typed_value("42"), "42");
+        assert_eq!(
+            dialect.typed_column_value(
+                "2024-01-01T12:34:56.5Z".to_string(),
+                ColumnType::TimestampTz
+            ),
+            "2024-01-01 12:34:56.500000"
+        );
+        assert_eq!(
+            dialect.typed_column_value("2024-01-01".to_string(), ColumnType::Date),
+            "2024-01-01"
+        );
+    }
+
+    #[test]
+    fn test_mysql_column_placeholders() {
+        let dialect = MySqlDialect;
+
+        assert_eq!(
+            dialect.typed_column_placeholder(1, ColumnType::BigInt),
+            "CAST(? AS SIGNED)"
+        );
+        assert_eq!(
+            dialect.typed_column_placeholder(2, ColumnType::TimestampTz),
+            "CAST(? AS DATETIME)"
+        );
+        assert_eq!(dialect.typed_column_placeholder(3, ColumnType::Text), "?");
+    }
+
+    #[test]
+    fn test_mysql_column_protection() {
+        let protection = <sqlx::MySql as QueryDatabase>::column_protection();
+
+        assert!(!protection.is_safe("mysql.user"));
+        assert!(!protection.is_safe("performance_schema.threads"));
+        assert!(!protection.is_safe("sys.processlist"));
+        assert!(!protection.is_safe("information_schema.tables"));
+        assert!(!protection.is_safe("MYSQL.USER"));
+
+        assert!(protection.is_safe("user_id"));
+        assert!(protection.is_safe("app.users.email"));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::ColumnType;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+pub fn quote_mysql_identifier(identifier: &str) -> String {
+    identifier
+        .split('.')
+        .map(|part| format!("`{}`", part.replace('`', "``")))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+pub fn get_mysql_type_casting(value: &str) -> Option<&'static str> {
+    match value.trim().to_lowercase().as_str() {
+        // Special values
+        value
+            if value == "null" || value == "nan" || value == "infinity" || value == "-infinity" =>
+        {
+            None
+        }
+
+        // JSON
+        value if value.starts_with('{') || value.starts_with('[') => {
+            serde_json::from_str::<serde_json::Value>(value)
+                .ok()
+                .map(|_| "JSON")
+        }
+
+        // Dates and Times
+        value if NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").is_ok() => {
+            Some("DATETIME")
+        }
+        value if NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok() => Some("DATE"),
+        value if NaiveTime::parse_from_str(value, "%H:%M:%S").is_ok() => Some("TIME"),
+        value if DateTime::parse_from_rfc3339(value).is_ok() => Some("DATETIME"),
+
+        // Numbers
+        value if value.parse::<i64>().is_ok() => Some("SIGNED"),
+        value if value.parse::<f64>().is_ok() => Some("DOUBLE"),
+
+        // Default - no type cast
+        _ => None,
+    }
+}
+
+/// Renders a date and time in the `YYYY-MM-DD HH:MM:SS[.ffffff]` form MySQL casts to `DATETIME`.
+///
+/// # Details
+///
+/// MySQL rejects the `T` separator with a `Z` or offset suffix of RFC 3339 values, so values
+/// with an offset are converted to UTC. Values that aren't dates and times are kept as they are.
+pub fn get_mysql_date_time_value(value: &str) -> String {
+    let trimmed = value.trim();
+    let date_time = DateTime::parse_from_rfc3339(trimmed)
+        .map(|date_time| date_time.naive_utc())
+        .or_else(|_| NaiveDateTime::parse_from_str(trimmed, "%Y-%m-%dT%H:%M:%S%.f"))
+        .or_else(|_| NaiveDateTime::parse_from_str(trimmed, "%Y-%m-%d %H:%M:%S%.f"));
+
+    match date_time {
+        Ok(date_time) if date_time.nanosecond() == 0 => {
+            date_time.format("%Y-%m-%d %H:%M:%S").to_string()
+        }
+        Ok(date_time) => date_time.format("%Y-%m-%d %H:%M:%S%.6f").to_string(),
+        Err(_) => value.to_string(),
+    }
+}
+
+pub fn get_mysql_column_type_casting(column_type: ColumnType) -> Option<&'static str> {
+    match column_type {
+        // Strings and UUIDs are compared in the column collation
+        ColumnType::Text | ColumnType::Uuid => None,
+        ColumnType::SmallInt | ColumnType::Integer | ColumnType::BigInt | ColumnType::Boolean => {
+            Some("SIGNED")
+        }
+        ColumnType::Real | ColumnType::Double => Some("DOUBLE"),
+        ColumnType::Numeric => Some("DECIMAL(65,30)"),
+        ColumnType::Date => Some("DATE"),
+        ColumnType::Time => Some("TIME"),
+        ColumnType::Timestamp | ColumnType::TimestampTz => Some("DATETIME"),
+        ColumnType::Json => Some("JSON"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_mysql_identifier() {
+        assert_eq!(quote_mysql_identifier("column"), "`column`");
+        assert_eq!(quote_mysql_identifier("app.users.id"), "`app`.`users`.`id`");
+        assert_eq!(quote_mysql_identifier("user`name"), "`user``name`");
+        assert_eq!(
+            quote_mysql_identifier("name`; DROP TABLE users; --"),
+            "`name``; DROP TABLE users; --`"
+        );
+    }
+
+    #[test]
+    fn test_numeric_types() {
+        assert_eq!(get_mysql_type_casting("42"), Some("SIGNED"));
+        assert_eq!(
+            get_mysql_type_casting("-9223372036854775808"),
+            Some("SIGNED")
+        );
+        assert_eq!(get_mysql_type_casting("3.14"), Some("DOUBLE"));
+        assert_eq!(get_mysql_type_casting("1.23E+4"), Some("DOUBLE"));
+    }
+
+    #[test]
+    fn test_date_time_types() {
+        assert_eq!(get_mysql_type_casting("2024-01-01"), Some("DATE"));
+        assert_eq!(get_mysql_type_casting("12:34:56"), Some("TIME"));
+        assert_eq!(
+            get_mysql_type_casting("2024-01-01 12:34:56"),
+            Some("DATETIME")
+        );
+        assert_eq!(
+            get_mysql_type_casting("2024-01-01T12:34:56Z"),
+            Some("DATETIME")
+        );
+    }
+
+    #[test]
+    fn test_date_time_values() {
+        assert_eq!(
+            get_mysql_date_time_value("2024-01-01T12:34:56Z"),
+            "2024-01-01 12:34:56"
+        );
+        assert_eq!(
+            get_mysql_date_time_value("2024-01-01T00:30:00-01:00"),
+            "2024-01-01 01:30:00"
+        );
+        assert_eq!(
+            get_mysql_date_time_value("2024-01-01T12:34:56.123456789Z"),
+            "2024-01-01 12:34:56.123456"
+        );
+        assert_eq!(
+            get_mysql_date_time_value("2024-01-01T12:34:56"),
+            "2024-01-01 12:34:56"
+        );
+        assert_eq!(get_mysql_date_time_value("yesterday"), "yesterday");
+    }
+
+    #[test]
+    fn test_json_types() {
+        assert_eq!(get_mysql_type_casting("{\"key\":\"value\"}"), Some("JSON"));
+        assert_eq!(get_mysql_type_casting("[1,2,3]"), Some("JSON"));
+        assert_eq!(get_mysql_type_casting("{invalid"), None);
+    }
+
+    #[test]
+    fn test_no_cast() {
+        assert_eq!(get_mysql_type_casting("john"), None);
+        assert_eq!(get_mysql_type_casting("true"), None);
+        assert_eq!(get_mysql_type_casting("NULL"), None);
+        assert_eq!(
+            get_mysql_type_casting("550e8400-e29b-41d4-a716-446655440000"),
+            None
+        );
+        assert_eq!(get_mysql_type_casting(""), None);
+    }
+}
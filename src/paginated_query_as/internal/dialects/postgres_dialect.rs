@@ -1,10 +1,14 @@
-use crate::paginated_query_as::internal::{get_postgres_type_casting, QueryDialect};
+use crate::paginated_query_as::internal::{
+    get_postgres_column_type_casting, get_postgres_row_estimate, get_postgres_type_casting,
+    quote_identifier, QueryDatabase, QueryDialect,
+};
+use crate::ColumnType;
 
 pub struct PostgresDialect;
 
 impl QueryDialect for PostgresDialect {
     fn quote_identifier(&self, ident: &str) -> String {
-        format!("\"{}\"", ident.replace('"', "\"\""))
+        quote_identifier(ident)
     }
 
     fn placeholder(&self, position: usize) -> String {
@@ -14,4 +18,26 @@ impl QueryDialect for PostgresDialect {
     fn type_cast(&self, value: &str) -> String {
         get_postgres_type_casting(value).to_string()
     }
+
+    fn column_type_cast(&self, column_type: ColumnType) -> String {
+        get_postgres_column_type_casting(column_type).to_string()
+    }
+
+    fn compares_text_strictly(&self) -> bool {
+        true
+    }
+
+    fn explain_query(&self, sql: &str) -> Option<String> {
+        Some(format!("EXPLAIN (FORMAT JSON) {}", sql))
+    }
+
+    fn row_estimate(&self, plan: &serde_json::Value) -> Option<i64> {
+        get_postgres_row_estimate(plan)
+    }
+}
+
+impl QueryDatabase for sqlx::Postgres {
+    fn dialect() -> Box<dyn QueryDialect> {
+        Box::new(PostgresDialect)
+    }
 }
@@ -1,4 +1,5 @@
 use crate::paginated_query_as::internal::DEFAULT_EMPTY_VALUE;
+use crate::ColumnType;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use sqlx::types::Uuid;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
@@ -65,10 +66,162 @@ pub fn get_postgres_type_casting(value: &str) -> &'static str {
     }
 }
 
+pub fn get_postgres_column_type_casting(column_type: ColumnType) -> &'static str {
+    match column_type {
+        ColumnType::Text => "::text",
+        ColumnType::SmallInt => "::smallint",
+        ColumnType::Integer => "::integer",
+        ColumnType::BigInt => "::bigint",
+        ColumnType::Real => "::real",
+        ColumnType::Double => "::double precision",
+        ColumnType::Numeric => "::numeric",
+        ColumnType::Boolean => "::boolean",
+        ColumnType::Uuid => "::uuid",
+        ColumnType::Date => "::date",
+        ColumnType::Time => "::time",
+        ColumnType::Timestamp => "::timestamp without time zone",
+        ColumnType::TimestampTz => "::timestamp with time zone",
+        ColumnType::Json => "::jsonb",
+    }
+}
+
+/// Reads the row estimate of the top plan node from an `EXPLAIN (FORMAT JSON)` output.
+pub fn get_postgres_row_estimate(plan: &serde_json::Value) -> Option<i64> {
+    let rows = plan.get(0)?.get("Plan")?.get("Plan Rows")?.as_f64()?;
+
+    Some(rows.round() as i64)
+}
+
+/// Renders a string literal, doubling its single quotes.
+pub fn get_postgres_string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Renders the `tsvector` of quoted columns as text, joined by spaces with NULLs as empty text.
+pub fn get_postgres_search_vector(config: &str, quoted_columns: &[String]) -> String {
+    let document = quoted_columns
+        .iter()
+        .map(|column| format!("coalesce({}::text, '')", column))
+        .collect::<Vec<String>>()
+        .join(" || ' ' || ");
+
+    format!(
+        "to_tsvector({}, {})",
+        get_postgres_string_literal(config),
+        document
+    )
+}
+
+/// Renders the `tsquery` of the search text bound to the placeholder, in web search syntax.
+pub fn get_postgres_search_query(config: &str, placeholder: &str) -> String {
+    format!(
+        "websearch_to_tsquery({}, {})",
+        get_postgres_string_literal(config),
+        placeholder
+    )
+}
+
+/// Renders the trigram match of a quoted column as text, filtered by similarity with a threshold.
+pub fn get_postgres_trigram_condition(
+    quoted_column: &str,
+    placeholder: &str,
+    threshold: Option<f64>,
+) -> String {
+    let quoted_column = format!("{}::text", quoted_column);
+
+    match threshold {
+        Some(threshold) => format!(
+            "({} % {} AND similarity({}, {}) >= {})",
+            quoted_column, placeholder, quoted_column, placeholder, threshold
+        ),
+        None => format!("{} % {}", quoted_column, placeholder),
+    }
+}
+
+/// Renders the trigram similarity of the most similar quoted column, as text.
+pub fn get_postgres_trigram_similarity(quoted_columns: &[String], placeholder: &str) -> String {
+    let similarities: Vec<String> = quoted_columns
+        .iter()
+        .map(|column| format!("similarity({}::text, {})", column, placeholder))
+        .collect();
+
+    match similarities.as_slice() {
+        [similarity] => similarity.clone(),
+        _ => format!("GREATEST({})", similarities.join(", ")),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_row_estimate() {
+        let plan = serde_json::json!([{
+            "Plan": {
+                "Node Type": "Seq Scan",
+                "Relation Name": "users",
+                "Plan Rows": 125000,
+                "Plan Width": 64
+            }
+        }]);
+
+        assert_eq!(get_postgres_row_estimate(&plan), Some(125000));
+        assert_eq!(get_postgres_row_estimate(&serde_json::json!([{}])), None);
+        assert_eq!(get_postgres_row_estimate(&serde_json::json!({})), None);
+    }
+
+    #[test]
+    fn test_search_vector_and_query() {
+        assert_eq!(
+            get_postgres_search_vector(
+                "english",
+                &["\"name\"".to_string(), "\"description\"".to_string()]
+            ),
+            "to_tsvector('english', coalesce(\"name\"::text, '') || ' ' || coalesce(\"description\"::text, ''))"
+        );
+        assert_eq!(
+            get_postgres_search_query("it's", "$2"),
+            "websearch_to_tsquery('it''s', $2)"
+        );
+    }
+
+    #[test]
+    fn test_trigram_condition_and_similarity() {
+        assert_eq!(
+            get_postgres_trigram_condition("\"name\"", "$1", None),
+            "\"name\"::text % $1"
+        );
+        assert_eq!(
+            get_postgres_trigram_condition("\"name\"", "$1", Some(0.45)),
+            "(\"name\"::text % $1 AND similarity(\"name\"::text, $1) >= 0.45)"
+        );
+        assert_eq!(
+            get_postgres_trigram_similarity(&["\"name\"".to_string()], "$2"),
+            "similarity(\"name\"::text, $2)"
+        );
+        assert_eq!(
+            get_postgres_trigram_similarity(
+                &["\"name\"".to_string(), "\"company\"".to_string()],
+                "$2"
+            ),
+            "GREATEST(similarity(\"name\"::text, $2), similarity(\"company\"::text, $2))"
+        );
+    }
+
+    #[test]
+    fn test_column_types() {
+        assert_eq!(get_postgres_column_type_casting(ColumnType::Text), "::text");
+        assert_eq!(
+            get_postgres_column_type_casting(ColumnType::Double),
+            "::double precision"
+        );
+        assert_eq!(
+            get_postgres_column_type_casting(ColumnType::TimestampTz),
+            "::timestamp with time zone"
+        );
+    }
+
     #[test]
     fn test_boolean_types() {
         // Standard boolean values
@@ -1,5 +1,79 @@
-pub trait QueryDialect {
+use crate::paginated_query_as::internal::{ColumnProtection, DEFAULT_LIKE_ESCAPE_SYMBOL};
+use crate::ColumnType;
+use sqlx::Database;
+
+pub trait QueryDialect: Send + Sync {
     fn quote_identifier(&self, ident: &str) -> String;
     fn placeholder(&self, position: usize) -> String;
     fn type_cast(&self, value: &str) -> String;
+
+    /// Renders the placeholder of a bound value together with the type cast it needs.
+    fn typed_placeholder(&self, position: usize, value: &str) -> String {
+        format!("{}{}", self.placeholder(position), self.type_cast(value))
+    }
+
+    /// Renders the text bound to a `typed_placeholder`, the value itself by default.
+    fn typed_value(&self, value: &str) -> String {
+        value.to_string()
+    }
+
+    /// Renders the text bound to a `typed_column_placeholder`, the value itself by default.
+    fn typed_column_value(&self, value: String, _column_type: ColumnType) -> String {
+        value
+    }
+
+    /// Type cast of a value bound to a column of a declared type, none by default.
+    fn column_type_cast(&self, _column_type: ColumnType) -> String {
+        String::new()
+    }
+
+    /// Wraps a query in the statement returning its plan as JSON, if the planner estimates rows.
+    fn explain_query(&self, _sql: &str) -> Option<String> {
+        None
+    }
+
+    /// Reads the estimated row count from a plan returned by `explain_query`.
+    fn row_estimate(&self, _plan: &serde_json::Value) -> Option<i64> {
+        None
+    }
+
+    /// Renders the ESCAPE clause of LIKE patterns escaped by `escape_like_pattern`.
+    fn like_escape_clause(&self) -> String {
+        format!(" ESCAPE '{}'", DEFAULT_LIKE_ESCAPE_SYMBOL)
+    }
+
+    /// Whether text bound uncast fails comparisons with columns of other types, as it does on
+    /// PostgreSQL, false by default.
+    fn compares_text_strictly(&self) -> bool {
+        false
+    }
+
+    /// Renders the cast of an SQL expression to text.
+    fn text_cast(&self, expression: &str) -> String {
+        format!("CAST({} AS TEXT)", expression)
+    }
+
+    /// Renders the concatenation of SQL expressions.
+    fn concat(&self, expressions: &[&str]) -> String {
+        expressions.join(" || ")
+    }
+
+    /// Renders the placeholder of a value bound to a column of a declared type.
+    fn typed_column_placeholder(&self, position: usize, column_type: ColumnType) -> String {
+        format!(
+            "{}{}",
+            self.placeholder(position),
+            self.column_type_cast(column_type)
+        )
+    }
+}
+
+/// Associates a sqlx database with the dialect its queries are rendered in.
+pub trait QueryDatabase: Database {
+    fn dialect() -> Box<dyn QueryDialect>;
+
+    /// Column protection guarding the system schemas of the database.
+    fn column_protection() -> ColumnProtection {
+        ColumnProtection::default()
+    }
 }
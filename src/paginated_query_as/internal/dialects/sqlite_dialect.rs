@@ -1,10 +1,10 @@
-use crate::paginated_query_as::internal::QueryDialect;
+use crate::paginated_query_as::internal::{quote_identifier, QueryDatabase, QueryDialect};
 
 pub struct SqliteDialect;
 
 impl QueryDialect for SqliteDialect {
     fn quote_identifier(&self, ident: &str) -> String {
-        format!("\"{}\"", ident.replace('"', "\"\""))
+        quote_identifier(ident)
     }
 
     fn placeholder(&self, _position: usize) -> String {
@@ -15,3 +15,9 @@ impl QueryDialect for SqliteDialect {
         String::new()
     }
 }
+
+impl QueryDatabase for sqlx::Sqlite {
+    fn dialect() -> Box<dyn QueryDialect> {
+        Box::new(SqliteDialect)
+    }
+}
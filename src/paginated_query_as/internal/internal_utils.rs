@@ -1,8 +1,9 @@
 use crate::paginated_query_as::internal::{
-    DEFAULT_DATE_RANGE_COLUMN_NAME, DEFAULT_MIN_PAGE_SIZE, DEFAULT_PAGE,
-    DEFAULT_SEARCH_COLUMN_NAMES, DEFAULT_SORT_COLUMN_NAME,
+    QueryDialect, DEFAULT_DATE_RANGE_COLUMN_NAME, DEFAULT_FILTER_VALUE_SEPARATOR_SYMBOL,
+    DEFAULT_LIKE_ESCAPE_SYMBOL, DEFAULT_MIN_PAGE_SIZE, DEFAULT_PAGE, DEFAULT_SEARCH_COLUMN_NAMES,
+    DEFAULT_SORT_COLUMN_NAME,
 };
-use crate::QuerySortDirection;
+use crate::{ColumnRegistry, ColumnType, QueryFilterOperator, QuerySearchMode, QuerySortDirection};
 use serde::Serialize;
 use serde_json::Value;
 
@@ -35,6 +36,8 @@ pub fn default_date_range_column() -> Option<String> {
     Some(DEFAULT_DATE_RANGE_COLUMN_NAME.to_string())
 }
 
+// Double quoted identifiers of the ANSI dialects, MySQL quotes with backticks
+#[cfg_attr(not(any(feature = "postgres", feature = "sqlite")), allow(dead_code))]
 pub fn quote_identifier(identifier: &str) -> String {
     identifier
         .split('.')
@@ -63,6 +66,111 @@ pub fn extract_digits_from_strings(val: impl Into<String>) -> String {
     val.into().chars().filter(|c| c.is_ascii_digit()).collect()
 }
 
+/// Splits a filter key such as `price[gte]`, `price__gte` or `price` into its column and operator.
+///
+/// # Returns
+///
+/// Returns `None` when the bracket syntax names an unknown operator.
+pub fn parse_filter_key(key: &str) -> Option<(&str, QueryFilterOperator)> {
+    if let Some(stripped) = key.strip_suffix(']') {
+        let (column, operator) = stripped.split_once('[')?;
+        return Some((column, QueryFilterOperator::parse(operator)?));
+    }
+
+    match key.rsplit_once("__") {
+        Some((column, operator)) => match QueryFilterOperator::parse(operator) {
+            Some(operator) => Some((column, operator)),
+            None => Some((key, QueryFilterOperator::Eq)),
+        },
+        None => Some((key, QueryFilterOperator::Eq)),
+    }
+}
+
+/// Checks that a filter key parses and targets a filterable column of the registry.
+pub fn is_valid_filter_key(key: &str, registry: &ColumnRegistry) -> bool {
+    parse_filter_key(key).is_some_and(|(column, _)| registry.is_filterable(column))
+}
+
+/// Formats the filter key for a column and operator, leaving equality filters as the bare column.
+pub fn get_filter_key(column: &str, operator: QueryFilterOperator) -> String {
+    match operator {
+        QueryFilterOperator::Eq => column.to_string(),
+        operator => format!("{}[{}]", column, operator.as_str()),
+    }
+}
+
+/// Splits the comma separated values of `in`, `not_in` and `between` filters.
+pub fn split_filter_values(value: &str) -> Vec<String> {
+    value
+        .split(DEFAULT_FILTER_VALUE_SEPARATOR_SYMBOL)
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+/// Escapes the `%` and `_` wildcards and the escape symbol itself, so a value matches literally in LIKE patterns.
+pub fn escape_like_pattern(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c == '%' || c == '_' || c == DEFAULT_LIKE_ESCAPE_SYMBOL {
+            escaped.push(DEFAULT_LIKE_ESCAPE_SYMBOL);
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Renders the search condition of a column and the value to bind to its placeholder.
+///
+/// # Arguments
+///
+/// * `dialect` - Dialect the condition is rendered in
+/// * `quoted_column` - The quoted SQL column
+/// * `column_type` - Declared SQL type of the column, if any
+/// * `search_mode` - How the search text matches the column
+/// * `search` - The search text
+/// * `position` - Position of the placeholder
+///
+/// # Returns
+///
+/// Returns a case-insensitive LIKE condition over the column, cast to text unless it's a
+/// text column or its type is unknown. Exact searches on typed columns compare typed values
+/// instead, returning `None` when the search text isn't a value of the type.
+pub fn get_search_condition(
+    dialect: &dyn QueryDialect,
+    quoted_column: &str,
+    column_type: Option<ColumnType>,
+    search_mode: QuerySearchMode,
+    search: &str,
+    position: usize,
+) -> Option<(String, String)> {
+    let column_type = column_type.filter(|column_type| *column_type != ColumnType::Text);
+
+    if let (QuerySearchMode::Exact, Some(column_type)) = (search_mode, column_type) {
+        let value = dialect.typed_column_value(column_type.parse_value(search)?, column_type);
+        let placeholder = dialect.typed_column_placeholder(position, column_type);
+
+        return Some((format!("{} = {}", quoted_column, placeholder), value));
+    }
+
+    let column = match column_type {
+        Some(_) => dialect.text_cast(quoted_column),
+        None => quoted_column.to_string(),
+    };
+    let column = match search_mode {
+        QuerySearchMode::WholeWord => dialect.concat(&["' '", &column, "' '"]),
+        _ => column,
+    };
+    let condition = format!(
+        "LOWER({}) LIKE LOWER({}){}",
+        column,
+        dialect.placeholder(position),
+        dialect.like_escape_clause()
+    );
+
+    Some((condition, search_mode.like_pattern(search)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,4 +324,65 @@ mod tests {
         assert_eq!(extract_digits_from_strings(String::from("12.34")), "1234");
         assert_eq!(extract_digits_from_strings("page=5"), "5");
     }
+
+    #[test]
+    fn test_parse_filter_key() {
+        assert_eq!(
+            parse_filter_key("status"),
+            Some(("status", QueryFilterOperator::Eq))
+        );
+        assert_eq!(
+            parse_filter_key("price[gte]"),
+            Some(("price", QueryFilterOperator::Gte))
+        );
+        assert_eq!(
+            parse_filter_key("price__lt"),
+            Some(("price", QueryFilterOperator::Lt))
+        );
+        assert_eq!(
+            parse_filter_key("deleted_at[is_not_null]"),
+            Some(("deleted_at", QueryFilterOperator::IsNotNull))
+        );
+        assert_eq!(
+            parse_filter_key("status__not_in"),
+            Some(("status", QueryFilterOperator::NotIn))
+        );
+
+        // Double underscores in column names are kept when not followed by an operator
+        assert_eq!(
+            parse_filter_key("legacy__code"),
+            Some(("legacy__code", QueryFilterOperator::Eq))
+        );
+
+        // Unknown bracket operators are rejected
+        assert_eq!(parse_filter_key("price[drop]"), None);
+        assert_eq!(parse_filter_key("price]"), None);
+    }
+
+    #[test]
+    fn test_get_filter_key() {
+        assert_eq!(get_filter_key("status", QueryFilterOperator::Eq), "status");
+        assert_eq!(
+            get_filter_key("price", QueryFilterOperator::Gte),
+            "price[gte]"
+        );
+        assert_eq!(
+            get_filter_key("status", QueryFilterOperator::NotIn),
+            "status[not_in]"
+        );
+    }
+
+    #[test]
+    fn test_escape_like_pattern() {
+        assert_eq!(escape_like_pattern("john"), "john");
+        assert_eq!(escape_like_pattern("50%_off"), "50!%!_off");
+        assert_eq!(escape_like_pattern("a!b\\c"), "a!!b\\c");
+    }
+
+    #[test]
+    fn test_split_filter_values() {
+        assert_eq!(split_filter_values("a,b , c"), vec!["a", "b", "c"]);
+        assert_eq!(split_filter_values("10,"), vec!["10"]);
+        assert!(split_filter_values(" , ").is_empty());
+    }
 }
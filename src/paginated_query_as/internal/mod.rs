@@ -1,15 +1,22 @@
 mod const_internal;
+mod cursor;
 mod deserializers;
 mod dialects;
 mod internal_utils;
 mod models_internal;
+mod odata_parser;
 mod protection;
+mod query_string;
 
 pub use const_internal::*;
+pub use cursor::*;
 pub use dialects::query_dialect::*;
 pub use internal_utils::*;
 pub use models_internal::*;
+pub use odata_parser::*;
 pub use protection::*;
+pub use query_string::*;
 
 pub use deserializers::*;
+#[allow(unused_imports)]
 pub use dialects::*;
@@ -1,14 +1,14 @@
 use crate::paginated_query_as::internal::{
     default_date_range_column, default_page, default_page_size, default_search_columns,
     default_sort_column, default_sort_direction, page_deserialize, page_size_deserialize,
-    search_columns_deserialize, search_deserialize,
+    search_columns_deserialize, search_deserialize, sorts_deserialize, DEFAULT_SORT_COLUMN_NAME,
 };
 
-use crate::QuerySortDirection;
+use crate::{QuerySearchMode, QuerySortDirection};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct QueryPaginationParams {
     #[serde(deserialize_with = "page_deserialize", default = "default_page")]
@@ -29,6 +29,22 @@ impl Default for QueryPaginationParams {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct QuerySortTerm {
+    pub column: String,
+    pub direction: QuerySortDirection,
+}
+
+impl QuerySortTerm {
+    pub fn new(column: impl Into<String>, direction: QuerySortDirection) -> Self {
+        Self {
+            column: column.into(),
+            direction,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "snake_case")]
 pub struct QuerySortParams {
@@ -36,6 +52,15 @@ pub struct QuerySortParams {
     pub sort_direction: QuerySortDirection,
     #[serde(default = "default_sort_column")]
     pub sort_column: String,
+    /// Ordered sort terms parsed from `sort=-created_at,name`, the first one mirrored
+    /// by `sort_column` and `sort_direction`.
+    #[serde(
+        rename = "sort",
+        default,
+        deserialize_with = "sorts_deserialize",
+        skip_serializing
+    )]
+    pub sorts: Vec<QuerySortTerm>,
 }
 
 impl Default for QuerySortParams {
@@ -43,11 +68,50 @@ impl Default for QuerySortParams {
         Self {
             sort_direction: default_sort_direction(),
             sort_column: default_sort_column(),
+            sorts: Vec::new(),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+// Params built from `sort` or from `sort_column` and `sort_direction` order by the same terms
+impl PartialEq for QuerySortParams {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_sort_terms() == other.get_sort_terms()
+    }
+}
+
+impl QuerySortParams {
+    /// Builds sort params from ordered terms, the first one becoming the primary sort.
+    pub fn from_terms(sorts: Vec<QuerySortTerm>) -> Self {
+        match sorts.first() {
+            Some(primary) => Self {
+                sort_direction: primary.direction.clone(),
+                sort_column: primary.column.clone(),
+                sorts,
+            },
+            None => Self::default(),
+        }
+    }
+
+    /// Checks whether the params sort by the default column, as when no sort is requested.
+    pub fn is_default_sort_column(&self) -> bool {
+        self.sorts.is_empty() && self.sort_column == DEFAULT_SORT_COLUMN_NAME
+    }
+
+    /// Returns the terms to order by, falling back to the single sort column.
+    pub fn get_sort_terms(&self) -> Vec<QuerySortTerm> {
+        if self.sorts.is_empty() {
+            vec![QuerySortTerm::new(
+                self.sort_column.clone(),
+                self.sort_direction.clone(),
+            )]
+        } else {
+            self.sorts.clone()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct QuerySearchParams {
     #[serde(deserialize_with = "search_deserialize")]
@@ -57,6 +121,9 @@ pub struct QuerySearchParams {
         default = "default_search_columns"
     )]
     pub search_columns: Option<Vec<String>>,
+    /// How the search text matches, `contains` by default.
+    #[serde(default)]
+    pub search_mode: QuerySearchMode,
 }
 
 impl Default for QuerySearchParams {
@@ -64,11 +131,12 @@ impl Default for QuerySearchParams {
         Self {
             search: None,
             search_columns: default_search_columns(),
+            search_mode: QuerySearchMode::default(),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct QueryDateRangeParams {
     pub date_after: Option<DateTime<Utc>>,
@@ -86,3 +154,10 @@ impl Default for QueryDateRangeParams {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryKeysetParams {
+    pub after_sort_value: Option<String>,
+    pub after_tie_breaker_value: Option<String>,
+}
@@ -0,0 +1,488 @@
+use crate::paginated_query_as::internal::{
+    QuerySortTerm, DEFAULT_MAX_FILTER_EXPRESSION_DEPTH, DEFAULT_SORT_COLUMN_NAME_SEPARATOR_SYMBOL,
+};
+use crate::{FilterExpr, PaginationError, QueryFilterOperator, QuerySortDirection};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    OpenParen,
+    CloseParen,
+    Comma,
+    /// A quoted string literal, unescaped.
+    Text(String),
+    /// A member name, keyword, or unquoted literal such as a number or a date.
+    Word(String),
+}
+
+enum Literal {
+    Null,
+    Value(String),
+}
+
+fn invalid_expression(reason: impl Into<String>) -> PaginationError {
+    PaginationError::InvalidExpression(reason.into())
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, PaginationError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&char) = chars.peek() {
+        match char {
+            char if char.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' | ',' => {
+                chars.next();
+                tokens.push(match char {
+                    '(' => Token::OpenParen,
+                    ')' => Token::CloseParen,
+                    _ => Token::Comma,
+                });
+            }
+            '\'' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        // Quotes are escaped by doubling them
+                        Some('\'') if chars.peek() == Some(&'\'') => {
+                            chars.next();
+                            text.push('\'');
+                        }
+                        Some('\'') => break,
+                        Some(char) => text.push(char),
+                        None => return Err(invalid_expression("unterminated string literal")),
+                    }
+                }
+                tokens.push(Token::Text(text));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&char) = chars.peek() {
+                    if char.is_whitespace() || matches!(char, '(' | ')' | ',' | '\'') {
+                        break;
+                    }
+                    word.push(char);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn is_member_name(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .is_some_and(|char| char.is_ascii_alphabetic() || char == '_')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+/// Checks that an unquoted word is a literal: a number, a date or time, or a GUID.
+fn is_unquoted_literal(word: &str) -> bool {
+    let starts_as_number = word
+        .trim_start_matches(['-', '+'])
+        .starts_with(|char: char| char.is_ascii_digit());
+    let is_guid = word.len() == 36
+        && word.char_indices().all(|(index, char)| match index {
+            8 | 13 | 18 | 23 => char == '-',
+            _ => char.is_ascii_hexdigit(),
+        });
+
+    (starts_as_number || is_guid)
+        && word
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || matches!(char, '-' | '+' | '.' | ':'))
+}
+
+struct ODataFilterParser {
+    tokens: Vec<Token>,
+    position: usize,
+    depth: usize,
+}
+
+impl ODataFilterParser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<Token, PaginationError> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or_else(|| invalid_expression("unexpected end of expression"))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn next_is_keyword(&mut self, keyword: &str) -> bool {
+        let is_keyword = matches!(self.peek(), Some(Token::Word(word)) if word == keyword);
+        if is_keyword {
+            self.position += 1;
+        }
+        is_keyword
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), PaginationError> {
+        match self.next()? {
+            token if token == expected => Ok(()),
+            token => Err(unexpected_token(&token)),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<FilterExpr, PaginationError> {
+        let mut exprs = vec![self.parse_and()?];
+        while self.next_is_keyword("or") {
+            exprs.push(self.parse_and()?);
+        }
+
+        Ok(match exprs.len() {
+            1 => exprs.remove(0),
+            _ => FilterExpr::Or(exprs),
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<FilterExpr, PaginationError> {
+        let mut exprs = vec![self.parse_not()?];
+        while self.next_is_keyword("and") {
+            exprs.push(self.parse_not()?);
+        }
+
+        Ok(match exprs.len() {
+            1 => exprs.remove(0),
+            _ => FilterExpr::And(exprs),
+        })
+    }
+
+    fn parse_not(&mut self) -> Result<FilterExpr, PaginationError> {
+        if self.next_is_keyword("not") {
+            return self
+                .nested(Self::parse_not)
+                .map(|expr| FilterExpr::Not(Box::new(expr)));
+        }
+        self.parse_primary()
+    }
+
+    /// Parses a nested expression, bounding the depth so that untrusted input can't
+    /// overflow the stack.
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<FilterExpr, PaginationError>,
+    ) -> Result<FilterExpr, PaginationError> {
+        self.depth += 1;
+        if self.depth > DEFAULT_MAX_FILTER_EXPRESSION_DEPTH {
+            return Err(invalid_expression("expression is nested too deeply"));
+        }
+        let expr = parse(self);
+        self.depth -= 1;
+        expr
+    }
+
+    fn parse_primary(&mut self) -> Result<FilterExpr, PaginationError> {
+        match self.next()? {
+            Token::OpenParen => {
+                let expr = self.nested(Self::parse_or)?;
+                self.expect(Token::CloseParen)?;
+                Ok(expr)
+            }
+            Token::Word(word) if self.peek() == Some(&Token::OpenParen) => {
+                self.parse_function(&word)
+            }
+            Token::Word(word) if is_member_name(&word) => self.parse_comparison(word),
+            token => Err(unexpected_token(&token)),
+        }
+    }
+
+    fn parse_comparison(&mut self, column: String) -> Result<FilterExpr, PaginationError> {
+        let operator = match self.next()? {
+            Token::Word(word) => word,
+            token => return Err(unexpected_token(&token)),
+        };
+
+        if operator == "in" {
+            return self.parse_in(column);
+        }
+
+        let operator = match operator.as_str() {
+            "eq" => QueryFilterOperator::Eq,
+            "ne" => QueryFilterOperator::Ne,
+            "gt" => QueryFilterOperator::Gt,
+            "ge" => QueryFilterOperator::Gte,
+            "lt" => QueryFilterOperator::Lt,
+            "le" => QueryFilterOperator::Lte,
+            _ => {
+                return Err(invalid_expression(format!(
+                    "unsupported operator: {}",
+                    operator
+                )))
+            }
+        };
+
+        match (self.parse_literal()?, operator) {
+            (Literal::Value(value), operator) => {
+                Ok(FilterExpr::leaf(column, operator, Some(value)))
+            }
+            (Literal::Null, QueryFilterOperator::Eq) => Ok(FilterExpr::leaf(
+                column,
+                QueryFilterOperator::IsNull,
+                None::<String>,
+            )),
+            (Literal::Null, QueryFilterOperator::Ne) => Ok(FilterExpr::leaf(
+                column,
+                QueryFilterOperator::IsNotNull,
+                None::<String>,
+            )),
+            (Literal::Null, _) => Err(invalid_expression(
+                "null can only be compared with eq or ne",
+            )),
+        }
+    }
+
+    /// Parses `column in ('a', 'b')` as equality conditions joined with `or`, as the values
+    /// may contain the separator of `in` filters.
+    fn parse_in(&mut self, column: String) -> Result<FilterExpr, PaginationError> {
+        self.expect(Token::OpenParen)?;
+
+        let mut exprs = Vec::new();
+        loop {
+            match self.parse_literal()? {
+                Literal::Value(value) => exprs.push(FilterExpr::leaf(
+                    column.clone(),
+                    QueryFilterOperator::Eq,
+                    Some(value),
+                )),
+                Literal::Null => return Err(invalid_expression("null can't be listed in in")),
+            }
+            match self.next()? {
+                Token::Comma => continue,
+                Token::CloseParen => break,
+                token => return Err(unexpected_token(&token)),
+            }
+        }
+
+        Ok(match exprs.len() {
+            1 => exprs.remove(0),
+            _ => FilterExpr::Or(exprs),
+        })
+    }
+
+    /// Parses a function call, only `contains(column, 'text')` being supported.
+    fn parse_function(&mut self, name: &str) -> Result<FilterExpr, PaginationError> {
+        if name != "contains" {
+            return Err(invalid_expression(format!(
+                "unsupported function: {}",
+                name
+            )));
+        }
+
+        self.expect(Token::OpenParen)?;
+        let column = match self.next()? {
+            Token::Word(word) if is_member_name(&word) => word,
+            token => return Err(unexpected_token(&token)),
+        };
+        self.expect(Token::Comma)?;
+        let text = match self.next()? {
+            Token::Text(text) => text,
+            token => return Err(unexpected_token(&token)),
+        };
+        self.expect(Token::CloseParen)?;
+
+        Ok(FilterExpr::leaf(
+            column,
+            QueryFilterOperator::Like,
+            Some(text),
+        ))
+    }
+
+    fn parse_literal(&mut self) -> Result<Literal, PaginationError> {
+        match self.next()? {
+            Token::Text(text) => Ok(Literal::Value(text)),
+            Token::Word(word) if word == "null" => Ok(Literal::Null),
+            Token::Word(word)
+                if word == "true" || word == "false" || is_unquoted_literal(&word) =>
+            {
+                Ok(Literal::Value(word))
+            }
+            token => Err(unexpected_token(&token)),
+        }
+    }
+}
+
+fn unexpected_token(token: &Token) -> PaginationError {
+    let token = match token {
+        Token::OpenParen => "(".to_string(),
+        Token::CloseParen => ")".to_string(),
+        Token::Comma => ",".to_string(),
+        Token::Text(text) => format!("'{}'", text.replace('\'', "''")),
+        Token::Word(word) => word.clone(),
+    };
+    invalid_expression(format!("unexpected {}", token))
+}
+
+/// Parses an OData `$filter` expression into a filter tree.
+///
+/// # Details
+///
+/// - Supports `eq`, `ne`, `gt`, `ge`, `lt`, `le` and `in`, combined with `and`, `or`, `not`
+///   and parentheses
+/// - Literals are quoted strings with `''` escaping quotes, numbers, dates, GUIDs, `true`,
+///   `false` and `null`, which is only compared with `eq` and `ne`
+/// - `contains(column, 'text')` is the only supported function, others are rejected
+/// - Columns aren't validated here but when the tree is rendered by `QueryBuilder`
+pub fn parse_odata_filter(input: &str) -> Result<FilterExpr, PaginationError> {
+    let mut parser = ODataFilterParser {
+        tokens: tokenize(input)?,
+        position: 0,
+        depth: 0,
+    };
+
+    let expr = parser.parse_or()?;
+    match parser.peek() {
+        Some(token) => Err(unexpected_token(token)),
+        None => Ok(expr),
+    }
+}
+
+/// Parses an OData `$orderby` such as `price desc, name` into sort terms.
+pub fn parse_odata_orderby(input: &str) -> Result<Vec<QuerySortTerm>, PaginationError> {
+    input
+        .split(DEFAULT_SORT_COLUMN_NAME_SEPARATOR_SYMBOL)
+        .map(str::trim)
+        .filter(|term| !term.is_empty())
+        .map(|term| {
+            let words: Vec<&str> = term.split_whitespace().collect();
+            let (column, direction) = match words.as_slice() {
+                [column] | [column, "asc"] => (*column, QuerySortDirection::Ascending),
+                [column, "desc"] => (*column, QuerySortDirection::Descending),
+                _ => return Err(invalid_expression(format!("invalid sort term: {}", term))),
+            };
+            if !is_member_name(column) {
+                return Err(invalid_expression(format!("invalid sort term: {}", term)));
+            }
+
+            Ok(QuerySortTerm::new(column, direction))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(column: &str, operator: QueryFilterOperator, value: &str) -> FilterExpr {
+        FilterExpr::leaf(column, operator, Some(value))
+    }
+
+    #[test]
+    fn test_parse_odata_filter() {
+        assert_eq!(
+            parse_odata_filter("price gt 10 and (status eq 'active' or status eq 'trial')")
+                .unwrap(),
+            FilterExpr::And(vec![
+                leaf("price", QueryFilterOperator::Gt, "10"),
+                FilterExpr::Or(vec![
+                    leaf("status", QueryFilterOperator::Eq, "active"),
+                    leaf("status", QueryFilterOperator::Eq, "trial"),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_odata_filter_precedence() {
+        // `and` binds tighter than `or`, and `not` tighter than both
+        assert_eq!(
+            parse_odata_filter("not a eq 1 or b le -2.5 and c ne null").unwrap(),
+            FilterExpr::Or(vec![
+                FilterExpr::Not(Box::new(leaf("a", QueryFilterOperator::Eq, "1"))),
+                FilterExpr::And(vec![
+                    leaf("b", QueryFilterOperator::Lte, "-2.5"),
+                    FilterExpr::leaf("c", QueryFilterOperator::IsNotNull, None::<String>),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_odata_filter_literals() {
+        assert_eq!(
+            parse_odata_filter("name eq 'O''Brien, Jr.'").unwrap(),
+            leaf("name", QueryFilterOperator::Eq, "O'Brien, Jr.")
+        );
+        assert_eq!(
+            parse_odata_filter("created_at ge 2024-01-01T00:00:00Z").unwrap(),
+            leaf(
+                "created_at",
+                QueryFilterOperator::Gte,
+                "2024-01-01T00:00:00Z"
+            )
+        );
+        assert_eq!(
+            parse_odata_filter("status in ('active', 'a,b')").unwrap(),
+            FilterExpr::Or(vec![
+                leaf("status", QueryFilterOperator::Eq, "active"),
+                leaf("status", QueryFilterOperator::Eq, "a,b"),
+            ])
+        );
+        assert_eq!(
+            parse_odata_filter("contains(name, 'john')").unwrap(),
+            leaf("name", QueryFilterOperator::Like, "john")
+        );
+    }
+
+    #[test]
+    fn test_parse_odata_filter_rejected() {
+        for (input, reason) in [
+            ("tolower(name) eq 'john'", "unsupported function: tolower"),
+            ("name has 'john'", "unsupported operator: has"),
+            ("price gt cost", "unexpected cost"),
+            ("price gt null", "null can only be compared with eq or ne"),
+            ("name eq 'john", "unterminated string literal"),
+            ("(price gt 10", "unexpected end of expression"),
+            ("price gt 10)", "unexpected )"),
+            ("address/city eq 'Paris'", "unexpected address/city"),
+            ("price gt 1; DROP TABLE users", "unexpected 1;"),
+            ("", "unexpected end of expression"),
+        ] {
+            assert!(
+                matches!(
+                    parse_odata_filter(input),
+                    Err(PaginationError::InvalidExpression(error)) if error == reason
+                ),
+                "{}",
+                input
+            );
+        }
+
+        let nested = format!("{}a eq 1{}", "(".repeat(100), ")".repeat(100));
+        assert!(matches!(
+            parse_odata_filter(&nested),
+            Err(PaginationError::InvalidExpression(error)) if error == "expression is nested too deeply"
+        ));
+    }
+
+    #[test]
+    fn test_parse_odata_orderby() {
+        assert_eq!(
+            parse_odata_orderby("price desc, name,id asc").unwrap(),
+            vec![
+                QuerySortTerm::new("price", QuerySortDirection::Descending),
+                QuerySortTerm::new("name", QuerySortDirection::Ascending),
+                QuerySortTerm::new("id", QuerySortDirection::Ascending),
+            ]
+        );
+        assert!(matches!(
+            parse_odata_orderby("price sideways"),
+            Err(PaginationError::InvalidExpression(_))
+        ));
+        assert!(matches!(
+            parse_odata_orderby("length(name)"),
+            Err(PaginationError::InvalidExpression(_))
+        ));
+    }
+}
@@ -16,3 +16,12 @@ pub static COLUMN_PROTECTION_BLOCKED_POSTGRES: [&str; 13] = [
     "pg_temp",
     "pg_internal",
 ];
+
+#[cfg(feature = "mysql")]
+pub static COLUMN_PROTECTION_BLOCKED_MYSQL: [&str; 4] = [
+    // System schemas
+    "mysql.",
+    "performance_schema.",
+    "sys.",
+    "information_schema.",
+];
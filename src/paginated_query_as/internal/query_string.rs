@@ -0,0 +1,416 @@
+use crate::paginated_query_as::internal::{
+    default_date_range_column, default_search_columns, QuerySortParams, DEFAULT_MIN_PAGE_SIZE,
+    DEFAULT_PAGE, DEFAULT_SEARCH_COLUMN_NAME_SEPARATOR_SYMBOL,
+    DEFAULT_SORT_COLUMN_NAME_SEPARATOR_SYMBOL, DEFAULT_SORT_DESCENDING_PREFIX_SYMBOL,
+};
+use crate::{QueryParams, QuerySearchMode, QuerySortDirection};
+use chrono::SecondsFormat;
+
+/// Symbols kept as is in query string components, on top of ASCII letters and digits.
+static QUERY_COMPONENT_SAFE_SYMBOLS: &[u8] = b"-._~,:";
+
+/// Percent-encodes a key or value of a query string.
+pub fn encode_query_component(component: &str) -> String {
+    let mut encoded = String::with_capacity(component.len());
+
+    for byte in component.bytes() {
+        if byte.is_ascii_alphanumeric() || QUERY_COMPONENT_SAFE_SYMBOLS.contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    encoded
+}
+
+/// Joins the pairs into a query string, without the leading `?`.
+pub fn encode_query_pairs(pairs: &[(String, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                "{}={}",
+                encode_query_component(key),
+                encode_query_component(value)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+/// Appends the query string to the URL, which may already have one.
+pub fn append_query_string(url: &str, query: &str) -> String {
+    match (query.is_empty(), url.contains('?')) {
+        (true, _) => url.to_string(),
+        (false, true) => format!("{}&{}", url, query),
+        (false, false) => format!("{}?{}", url, query),
+    }
+}
+
+/// Returns the query string parameters the params are parsed from, defaults left out.
+///
+/// # Details
+///
+/// - The sort is given as `sort=-created_at,name`, whether the params were built from
+///   `sort` or from `sort_column` and `sort_direction`
+/// - Parameters come in a fixed order: pagination, sort, search, date range, keyset,
+///   cursor, filter expression as JSON, then the filters sorted by key
+/// - Filters without a value are left out, as they don't filter anything
+pub fn query_params_to_pairs<T>(params: &QueryParams<T>) -> Vec<(String, String)> {
+    let mut pairs: Vec<(&str, String)> = Vec::new();
+
+    if params.pagination.page != DEFAULT_PAGE {
+        pairs.push(("page", params.pagination.page.to_string()));
+    }
+    if params.pagination.page_size != DEFAULT_MIN_PAGE_SIZE {
+        pairs.push(("page_size", params.pagination.page_size.to_string()));
+    }
+
+    let sort_terms = params.sort.get_sort_terms();
+    if sort_terms != QuerySortParams::default().get_sort_terms() {
+        let sort = sort_terms
+            .iter()
+            .map(|term| match term.direction {
+                QuerySortDirection::Ascending => term.column.clone(),
+                QuerySortDirection::Descending => {
+                    format!("{}{}", DEFAULT_SORT_DESCENDING_PREFIX_SYMBOL, term.column)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(DEFAULT_SORT_COLUMN_NAME_SEPARATOR_SYMBOL);
+        pairs.push(("sort", sort));
+    }
+
+    if let Some(search) = &params.search.search {
+        pairs.push(("search", search.clone()));
+    }
+    if params.search.search_columns != default_search_columns() {
+        let search_columns = params
+            .search
+            .search_columns
+            .as_deref()
+            .unwrap_or_default()
+            .join(DEFAULT_SEARCH_COLUMN_NAME_SEPARATOR_SYMBOL);
+        pairs.push(("search_columns", search_columns));
+    }
+    if params.search.search_mode != QuerySearchMode::default() {
+        pairs.push((
+            "search_mode",
+            params.search.search_mode.as_str().to_string(),
+        ));
+    }
+
+    if let Some(date_after) = params.date_range.date_after {
+        pairs.push((
+            "date_after",
+            date_after.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        ));
+    }
+    if let Some(date_before) = params.date_range.date_before {
+        pairs.push((
+            "date_before",
+            date_before.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        ));
+    }
+    if params.date_range.date_column != default_date_range_column() {
+        if let Some(date_column) = &params.date_range.date_column {
+            pairs.push(("date_column", date_column.clone()));
+        }
+    }
+
+    if let Some(after_sort_value) = &params.keyset.after_sort_value {
+        pairs.push(("after_sort_value", after_sort_value.clone()));
+    }
+    if let Some(after_tie_breaker_value) = &params.keyset.after_tie_breaker_value {
+        pairs.push(("after_tie_breaker_value", after_tie_breaker_value.clone()));
+    }
+    if let Some(cursor) = &params.cursor {
+        pairs.push(("cursor", cursor.clone()));
+    }
+    if let Some(filter) = &params.filter {
+        pairs.push(("filter", serde_json::to_string(filter).unwrap_or_default()));
+    }
+
+    let mut filters: Vec<(&String, &String)> = params
+        .filters
+        .iter()
+        .filter_map(|(key, value)| Some((key, value.as_ref()?)))
+        .collect();
+    filters.sort();
+
+    pairs
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .chain(
+            filters
+                .into_iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paginated_query_as::internal::{QueryKeysetParams, QuerySortTerm};
+    use crate::{
+        FilterExpr, FlatQueryParams, QueryFilterOperator, QueryParamsBuilder, QuerySortDirection,
+    };
+    use chrono::{DateTime, TimeZone, Utc};
+    use proptest::prelude::*;
+    use serde::Serialize;
+
+    #[derive(Default, Serialize)]
+    struct TestModel {
+        name: String,
+        status: String,
+        created_at: String,
+    }
+
+    #[test]
+    fn test_encode_query_component() {
+        assert_eq!(
+            encode_query_component("-created_at,name"),
+            "-created_at,name"
+        );
+        assert_eq!(encode_query_component("status[in]"), "status%5Bin%5D");
+        assert_eq!(encode_query_component("john doe&co"), "john%20doe%26co");
+        assert_eq!(encode_query_component("café+"), "caf%C3%A9%2B");
+    }
+
+    #[test]
+    fn test_query_params_to_pairs() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(2, 25)
+            .with_sort("name", QuerySortDirection::Ascending)
+            .with_search("john", vec!["name"])
+            .with_date_range(
+                Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+                None,
+                None::<String>,
+            )
+            .with_filter("status", Some("active"))
+            .with_filter("name", None::<String>)
+            .build();
+
+        assert_eq!(
+            encode_query_pairs(&query_params_to_pairs(&params)),
+            "page=2&page_size=25&sort=name&search=john\
+             &search_columns=name&date_after=2024-01-01T00:00:00Z&status=active"
+        );
+    }
+
+    #[test]
+    fn test_default_query_params_to_pairs() {
+        let params = QueryParamsBuilder::<TestModel>::new().build();
+
+        assert!(query_params_to_pairs(&params).is_empty());
+    }
+
+    fn parse_query_string(query: &str) -> QueryParams<'static, TestModel> {
+        serde_urlencoded::from_str::<FlatQueryParams>(query)
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn test_query_string_canonical_order() {
+        let params = parse_query_string("status=active&sort_column=name&page=3&name[ne]=bob");
+
+        assert_eq!(
+            params.to_query_string(),
+            "page=3&sort=-name&name%5Bne%5D=bob&status=active"
+        );
+        assert_eq!(
+            parse_query_string("name[ne]=bob&page=3&status=active&sort=-name").to_query_string(),
+            params.to_query_string()
+        );
+    }
+
+    #[test]
+    fn test_query_string_lossy_round_trip() {
+        let mut params = QueryParamsBuilder::<TestModel>::new()
+            .with_search(
+                format!("  100% rust_lang,  {}", "a".repeat(120)),
+                vec![" name"],
+            )
+            .with_sorts(vec![
+                ("created_at,name", QuerySortDirection::Ascending),
+                ("+status", QuerySortDirection::Descending),
+            ])
+            .with_filter("status", None::<String>)
+            .build();
+        params.pagination.page = 0;
+        params.pagination.page_size = 5;
+        params.search.search_columns = None;
+        params.date_range.date_column = None;
+
+        let parsed = parse_query_string(&params.to_query_string());
+
+        assert_eq!(parsed.pagination.page, 1);
+        assert_eq!(parsed.pagination.page_size, 10);
+        assert_eq!(
+            parsed.search.search,
+            Some(format!("100 rustlang {}", "a".repeat(87)))
+        );
+        assert_eq!(parsed.search.search_columns, Some(vec![]));
+        assert_eq!(
+            parsed.sort.get_sort_terms(),
+            vec![
+                QuerySortTerm::new("created_at", QuerySortDirection::Ascending),
+                QuerySortTerm::new("name", QuerySortDirection::Ascending),
+                QuerySortTerm::new("+status", QuerySortDirection::Descending),
+            ]
+        );
+        assert!(parsed.filters.is_empty());
+        assert_eq!(
+            parsed.date_range.date_column,
+            QueryParams::<TestModel>::default().date_range.date_column
+        );
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_search("rust", vec![" name ", "status,created_at"])
+            .with_sort("+status", QuerySortDirection::Ascending)
+            .build();
+        let parsed = parse_query_string(&params.to_query_string());
+
+        assert_eq!(
+            parsed.search.search_columns,
+            Some(vec![
+                "name".to_string(),
+                "status".to_string(),
+                "created_at".to_string(),
+            ])
+        );
+        assert_eq!(
+            parsed.sort.get_sort_terms(),
+            vec![QuerySortTerm::new("status", QuerySortDirection::Ascending)]
+        );
+    }
+
+    static RESERVED_KEYS: [&str; 15] = [
+        "page",
+        "page_size",
+        "sort",
+        "sort_column",
+        "sort_direction",
+        "search",
+        "search_columns",
+        "search_mode",
+        "date_after",
+        "date_before",
+        "date_column",
+        "after_sort_value",
+        "after_tie_breaker_value",
+        "cursor",
+        "filter",
+    ];
+
+    fn date_strategy() -> impl Strategy<Value = Option<DateTime<Utc>>> {
+        proptest::option::of(
+            (0i64..4_102_444_800, 0u32..1_000_000_000)
+                .prop_map(|(secs, nanos)| Utc.timestamp_opt(secs, nanos).unwrap()),
+        )
+    }
+
+    fn filter_expr_strategy() -> impl Strategy<Value = FilterExpr> {
+        let leaf = (
+            "[a-z_]{1,12}",
+            prop_oneof![
+                Just(QueryFilterOperator::Eq),
+                Just(QueryFilterOperator::Ilike),
+                Just(QueryFilterOperator::In),
+                Just(QueryFilterOperator::IsNull),
+            ],
+            proptest::option::of(".*"),
+        )
+            .prop_map(|(column, operator, value)| FilterExpr::Leaf {
+                column,
+                operator,
+                value,
+            });
+
+        leaf.prop_recursive(3, 12, 3, |inner| {
+            prop_oneof![
+                proptest::collection::vec(inner.clone(), 0..3).prop_map(FilterExpr::And),
+                proptest::collection::vec(inner.clone(), 0..3).prop_map(FilterExpr::Or),
+                inner.prop_map(|expr| FilterExpr::Not(Box::new(expr))),
+            ]
+        })
+    }
+
+    // Values normalized while parsing are kept out, see `test_query_string_lossy_round_trip`
+    prop_compose! {
+        fn query_params_strategy()(
+            page in 1i64..100_000,
+            page_size in 10i64..=10_000,
+            sorts in proptest::collection::vec(("[a-z_]{1,12}", any::<bool>()), 0..3),
+            search in proptest::option::of("[a-z0-9]{1,10}( [a-z0-9-]{1,10}){0,3}"),
+            search_columns in proptest::collection::vec("[a-z_]{1,12}", 0..3),
+            search_mode in prop_oneof![
+                Just(QuerySearchMode::Contains),
+                Just(QuerySearchMode::StartsWith),
+                Just(QuerySearchMode::WholeWord),
+            ],
+            date_after in date_strategy(),
+            date_before in date_strategy(),
+            date_column in "[a-z_]{1,12}",
+            keyset in proptest::option::of((".*", ".*")),
+            cursor in proptest::option::of("[A-Za-z0-9_.-]{1,40}"),
+            filter in proptest::option::of(filter_expr_strategy()),
+            filters in proptest::collection::hash_map(
+                "[a-z_]{1,12}(\\[(in|ne|gte)\\])?".prop_filter("reserved key", |key| {
+                    !RESERVED_KEYS.contains(&key.as_str())
+                }),
+                ".*",
+                0..4,
+            ),
+        ) -> QueryParams<'static, TestModel> {
+            let sorts = sorts
+                .into_iter()
+                .map(|(column, ascending)| match ascending {
+                    true => (column, QuerySortDirection::Ascending),
+                    false => (column, QuerySortDirection::Descending),
+                });
+            let mut builder = QueryParamsBuilder::<TestModel>::new()
+                .with_pagination(page, page_size)
+                .with_sorts(sorts)
+                .with_date_range(date_after, date_before, Some(date_column));
+            if let Some(search) = search {
+                builder = builder
+                    .with_search(search, search_columns)
+                    .with_search_mode(search_mode);
+            }
+            if let Some(cursor) = cursor {
+                builder = builder.with_cursor(cursor);
+            }
+            if let Some(filter) = filter {
+                builder = builder.with_filter_expr(filter);
+            }
+            let mut params = builder.build();
+            // Set directly, as the builder skips filters on columns of other models
+            params.filters = filters
+                .into_iter()
+                .map(|(key, value)| (key, Some(value)))
+                .collect();
+            if let Some((after_sort_value, after_tie_breaker_value)) = keyset {
+                params.keyset = QueryKeysetParams {
+                    after_sort_value: Some(after_sort_value),
+                    after_tie_breaker_value: Some(after_tie_breaker_value),
+                };
+            }
+            params
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_query_string_round_trip(params in query_params_strategy()) {
+            let query = params.to_query_string();
+
+            prop_assert_eq!(parse_query_string(&query), params);
+            prop_assert_eq!(parse_query_string(&query).to_query_string(), query);
+        }
+    }
+}
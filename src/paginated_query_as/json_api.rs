@@ -0,0 +1,503 @@
+use crate::paginated_query_as::internal::{
+    append_query_string, default_page, default_page_size, encode_query_pairs, page_deserialize,
+    page_size_deserialize, sorts_deserialize, QueryPaginationParams, QuerySortParams,
+    QuerySortTerm, DEFAULT_FILTER_VALUE_SEPARATOR_SYMBOL, DEFAULT_MIN_PAGE_SIZE, DEFAULT_PAGE,
+    DEFAULT_SORT_COLUMN_NAME_SEPARATOR_SYMBOL, DEFAULT_SORT_DESCENDING_PREFIX_SYMBOL,
+};
+use crate::{PaginatedLinks, PaginatedResponse, QueryParams, QuerySortDirection};
+use serde::ser::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Query parameters in the JSON:API syntax, an alternative to `FlatQueryParams`.
+///
+/// | Parameter              | Maps to                                   |
+/// |------------------------|-------------------------------------------|
+/// | `page[number]`         | `page`                                    |
+/// | `page[size]`           | `page_size`                               |
+/// | `page[cursor]`         | `cursor`                                  |
+/// | `sort=-created_at,name`| `sort=-created_at,name`                   |
+/// | `filter[status]=active`| `status=active`                           |
+/// | `filter[age][gte]=18`  | `age[gte]=18`                             |
+/// | `fields[users]=id,name`| Attributes kept by `JsonApiDocument`      |
+///
+/// Other parameters are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::{Serialize};
+/// use sqlx_paginated::{JsonApiQueryParams, QueryParams};
+///
+/// #[derive(Serialize, Default)]
+/// struct UserExample {
+///     name: String,
+///     age: i32,
+/// }
+///
+/// let json_api_params: JsonApiQueryParams =
+///     serde_urlencoded::from_str("page[number]=2&sort=-name&filter[age][gte]=18").unwrap();
+/// let params: QueryParams<UserExample> = json_api_params.into();
+///
+/// assert_eq!(params.pagination.page, 2);
+/// assert_eq!(params.sort.sort_column, "name");
+/// assert_eq!(params.filters.get("age[gte]"), Some(&Some("18".to_string())));
+/// ```
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(from = "JsonApiRawQueryParams")]
+pub struct JsonApiQueryParams {
+    pub pagination: QueryPaginationParams,
+    pub cursor: Option<String>,
+    pub sorts: Vec<QuerySortTerm>,
+    /// Filters keyed as in `QueryParams`, e.g. `age[gte]` for `filter[age][gte]`.
+    pub filters: HashMap<String, Option<String>>,
+    /// Sparse fieldsets, the attributes to keep per resource type.
+    pub fields: HashMap<String, Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct JsonApiRawQueryParams {
+    #[serde(
+        rename = "page[number]",
+        deserialize_with = "page_deserialize",
+        default = "default_page"
+    )]
+    page_number: i64,
+    #[serde(
+        rename = "page[size]",
+        deserialize_with = "page_size_deserialize",
+        default = "default_page_size"
+    )]
+    page_size: i64,
+    #[serde(rename = "page[cursor]")]
+    cursor: Option<String>,
+    #[serde(default, deserialize_with = "sorts_deserialize")]
+    sort: Vec<QuerySortTerm>,
+    #[serde(flatten)]
+    parameters: HashMap<String, String>,
+}
+
+impl From<JsonApiRawQueryParams> for JsonApiQueryParams {
+    fn from(raw: JsonApiRawQueryParams) -> Self {
+        let mut filters = HashMap::new();
+        let mut fields = HashMap::new();
+
+        for (key, value) in raw.parameters {
+            if let Some((column, operator)) = key
+                .strip_prefix("filter[")
+                .and_then(|key| key.split_once(']'))
+            {
+                filters.insert(format!("{}{}", column, operator), Some(value));
+            } else if let Some(resource_type) = key
+                .strip_prefix("fields[")
+                .and_then(|key| key.strip_suffix(']'))
+            {
+                let columns = value
+                    .split(DEFAULT_FILTER_VALUE_SEPARATOR_SYMBOL)
+                    .map(str::trim)
+                    .filter(|column| !column.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                fields.insert(resource_type.to_string(), columns);
+            }
+        }
+
+        Self {
+            pagination: QueryPaginationParams {
+                page: raw.page_number,
+                page_size: raw.page_size,
+            },
+            cursor: raw.cursor,
+            sorts: raw.sort,
+            filters,
+            fields,
+        }
+    }
+}
+
+impl<'q, T> From<JsonApiQueryParams> for QueryParams<'q, T> {
+    fn from(params: JsonApiQueryParams) -> Self {
+        QueryParams {
+            pagination: params.pagination,
+            sort: QuerySortParams::from_terms(params.sorts),
+            cursor: params.cursor,
+            filters: params.filters,
+            ..Default::default()
+        }
+    }
+}
+
+impl JsonApiQueryParams {
+    /// Serializes the params back into a JSON:API query string, in the same canonical way
+    /// as `QueryParams::to_query_string`.
+    pub fn to_query_string(&self) -> String {
+        let mut pairs = Vec::new();
+
+        if self.pagination.page != DEFAULT_PAGE {
+            pairs.push(("page[number]".to_string(), self.pagination.page.to_string()));
+        }
+        if self.pagination.page_size != DEFAULT_MIN_PAGE_SIZE {
+            pairs.push((
+                "page[size]".to_string(),
+                self.pagination.page_size.to_string(),
+            ));
+        }
+        if let Some(cursor) = &self.cursor {
+            pairs.push(("page[cursor]".to_string(), cursor.clone()));
+        }
+        if !self.sorts.is_empty() {
+            let sort = self
+                .sorts
+                .iter()
+                .map(|term| match term.direction {
+                    QuerySortDirection::Ascending => term.column.clone(),
+                    QuerySortDirection::Descending => {
+                        format!("{}{}", DEFAULT_SORT_DESCENDING_PREFIX_SYMBOL, term.column)
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(DEFAULT_SORT_COLUMN_NAME_SEPARATOR_SYMBOL);
+            pairs.push(("sort".to_string(), sort));
+        }
+
+        let mut filters: Vec<(String, String)> = self
+            .filters
+            .iter()
+            .filter_map(|(key, value)| {
+                let key = match key.split_once('[') {
+                    Some((column, operator)) => format!("filter[{}][{}", column, operator),
+                    None => format!("filter[{}]", key),
+                };
+                Some((key, value.clone()?))
+            })
+            .collect();
+        filters.sort();
+
+        let mut fields: Vec<(String, String)> = self
+            .fields
+            .iter()
+            .map(|(resource_type, columns)| {
+                (
+                    format!("fields[{}]", resource_type),
+                    columns.join(DEFAULT_FILTER_VALUE_SEPARATOR_SYMBOL),
+                )
+            })
+            .collect();
+        fields.sort();
+
+        pairs.extend(filters);
+        pairs.extend(fields);
+        encode_query_pairs(&pairs)
+    }
+}
+
+/// A page of records as a JSON:API document, with `data`, `meta` and `links` members.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::{Serialize};
+/// use sqlx_paginated::{JsonApiDocument, JsonApiQueryParams, PaginatedResponse};
+///
+/// #[derive(Serialize)]
+/// struct UserExample {
+///     id: i64,
+///     name: String,
+///     email: String,
+/// }
+///
+/// let params: JsonApiQueryParams = serde_urlencoded::from_str("fields[users]=name").unwrap();
+/// let response = PaginatedResponse {
+///     records: vec![UserExample { id: 1, name: "John".to_string(), email: "john@example.com".to_string() }],
+///     pagination: Some(params.pagination.clone()),
+///     total: Some(1),
+///     total_pages: Some(1),
+///     total_exact: Some(true),
+///     has_next_page: false,
+///     has_previous_page: false,
+///     next_cursor: None,
+///     prev_cursor: None,
+///     links: None,
+/// };
+///
+/// let document = JsonApiDocument::from_response(&response, "users", &params, Some("/users")).unwrap();
+/// assert_eq!(
+///     serde_json::to_value(&document).unwrap(),
+///     serde_json::json!({
+///         "data": [{ "type": "users", "id": "1", "attributes": { "name": "John" } }],
+///         "meta": { "total": 1, "total_pages": 1, "total_exact": true, "page": 1, "page_size": 10 },
+///         "links": { "first": "/users?fields%5Busers%5D=name", "last": "/users?fields%5Busers%5D=name" }
+///     })
+/// );
+/// ```
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct JsonApiDocument {
+    pub data: Vec<JsonApiResource>,
+    pub meta: JsonApiMeta,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<PaginatedLinks>,
+}
+
+/// A record as a JSON:API resource object.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct JsonApiResource {
+    #[serde(rename = "type")]
+    pub resource_type: String,
+    /// The `id` field of the record, as a string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub attributes: Map<String, Value>,
+}
+
+/// Pagination details of a JSON:API document.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct JsonApiMeta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_exact: Option<bool>,
+    pub page: i64,
+    pub page_size: i64,
+}
+
+impl JsonApiDocument {
+    /// Builds the document of a paginated response.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - Response of `PaginatedQueryBuilder::fetch_paginated`
+    /// * `resource_type` - JSON:API type of the records, e.g. `users`
+    /// * `params` - Params of the request, for its sparse fieldsets and the links
+    /// * `base_url` - URL the links are built from, no links when `None`
+    ///
+    /// # Details
+    ///
+    /// - Records are serialized to objects, their `id` field becoming the resource id
+    /// - `fields[type]` of the resource type restricts the attributes
+    /// - Links carry the sort, filters and fieldsets of the params, and point to cursors
+    ///   when the response has them
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a record doesn't serialize to a JSON object
+    pub fn from_response<T: Serialize>(
+        response: &PaginatedResponse<T>,
+        resource_type: &str,
+        params: &JsonApiQueryParams,
+        base_url: Option<&str>,
+    ) -> Result<Self, serde_json::Error> {
+        let fields = params.fields.get(resource_type);
+        let data = response
+            .records
+            .iter()
+            .map(|record| {
+                let Value::Object(mut attributes) = serde_json::to_value(record)? else {
+                    return Err(serde_json::Error::custom(
+                        "JSON:API resources must serialize to objects",
+                    ));
+                };
+                let id = attributes.remove("id").and_then(|id| match id {
+                    Value::Null => None,
+                    Value::String(id) => Some(id),
+                    id => Some(id.to_string()),
+                });
+                if let Some(fields) = fields {
+                    attributes.retain(|attribute, _| fields.contains(attribute));
+                }
+
+                Ok(JsonApiResource {
+                    resource_type: resource_type.to_string(),
+                    id,
+                    attributes,
+                })
+            })
+            .collect::<Result<Vec<JsonApiResource>, serde_json::Error>>()?;
+
+        let pagination = response.pagination.clone().unwrap_or_default();
+        let links = base_url.map(|base_url| {
+            let cursor_pagination =
+                response.next_cursor.is_some() || response.prev_cursor.is_some();
+
+            PaginatedLinks::from_response(response, cursor_pagination, |page, cursor| {
+                let params = JsonApiQueryParams {
+                    pagination: QueryPaginationParams {
+                        page,
+                        page_size: pagination.page_size,
+                    },
+                    cursor: cursor.map(str::to_string),
+                    ..params.clone()
+                };
+                append_query_string(base_url, &params.to_query_string())
+            })
+        });
+
+        Ok(Self {
+            data,
+            meta: JsonApiMeta {
+                total: response.total,
+                total_pages: response.total_pages,
+                total_exact: response.total_exact,
+                page: pagination.page,
+                page_size: pagination.page_size,
+            },
+            links,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Default, Serialize)]
+    struct TestModel {
+        id: i64,
+        name: String,
+        email: String,
+        age: i32,
+    }
+
+    fn parse(query: &str) -> JsonApiQueryParams {
+        serde_urlencoded::from_str(query).unwrap()
+    }
+
+    fn test_response(page: i64, total: i64) -> PaginatedResponse<TestModel> {
+        PaginatedResponse {
+            records: vec![TestModel {
+                id: 7,
+                name: "John".to_string(),
+                email: "john@example.com".to_string(),
+                age: 30,
+            }],
+            pagination: Some(QueryPaginationParams {
+                page,
+                page_size: 10,
+            }),
+            total: Some(total),
+            total_pages: Some((total + 9) / 10),
+            total_exact: Some(true),
+            has_next_page: page * 10 < total,
+            has_previous_page: page > 1,
+            next_cursor: None,
+            prev_cursor: None,
+            links: None,
+        }
+    }
+
+    #[test]
+    fn test_json_api_params_parsing() {
+        let params = parse(
+            "page[number]=3&page[size]=20&sort=-age,name&filter[name]=john\
+             &filter[age][gte]=18&fields[users]=name,%20email&include=posts",
+        );
+
+        assert_eq!(params.pagination.page, 3);
+        assert_eq!(params.pagination.page_size, 20);
+        assert_eq!(
+            params.sorts,
+            vec![
+                QuerySortTerm::new("age", QuerySortDirection::Descending),
+                QuerySortTerm::new("name", QuerySortDirection::Ascending),
+            ]
+        );
+        assert_eq!(params.filters.len(), 2);
+        assert_eq!(params.filters.get("name"), Some(&Some("john".to_string())));
+        assert_eq!(
+            params.filters.get("age[gte]"),
+            Some(&Some("18".to_string()))
+        );
+        assert_eq!(
+            params.fields.get("users"),
+            Some(&vec!["name".to_string(), "email".to_string()])
+        );
+
+        let query_params: QueryParams<TestModel> = params.into();
+        assert_eq!(query_params.pagination.page, 3);
+        assert_eq!(query_params.sort.sort_column, "age");
+        assert_eq!(
+            query_params.sort.sort_direction,
+            QuerySortDirection::Descending
+        );
+        assert_eq!(query_params.filters.len(), 2);
+    }
+
+    #[test]
+    fn test_json_api_params_defaults() {
+        let params = parse("");
+        let query_params: QueryParams<TestModel> = params.clone().into();
+
+        assert_eq!(params.to_query_string(), "");
+        assert_eq!(query_params, QueryParams::default());
+    }
+
+    #[test]
+    fn test_json_api_params_to_query_string() {
+        let query = "page[number]=2&sort=-age&filter[status]=active&filter[age][gte]=18\
+                     &fields[users]=name&page[cursor]=abc";
+        let params = parse(query);
+
+        assert_eq!(
+            params.to_query_string(),
+            "page%5Bnumber%5D=2&page%5Bcursor%5D=abc&sort=-age&filter%5Bage%5D%5Bgte%5D=18\
+             &filter%5Bstatus%5D=active&fields%5Busers%5D=name"
+        );
+        assert_eq!(parse(&params.to_query_string()), params);
+    }
+
+    #[test]
+    fn test_json_api_document() {
+        let params = parse("page[number]=2&filter[status]=active&fields[users]=name,age");
+        let document =
+            JsonApiDocument::from_response(&test_response(2, 25), "users", &params, Some("/users"))
+                .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&document).unwrap(),
+            serde_json::json!({
+                "data": [{
+                    "type": "users",
+                    "id": "7",
+                    "attributes": { "name": "John", "age": 30 }
+                }],
+                "meta": { "total": 25, "total_pages": 3, "total_exact": true, "page": 2, "page_size": 10 },
+                "links": {
+                    "first": "/users?filter%5Bstatus%5D=active&fields%5Busers%5D=name,age",
+                    "prev": "/users?filter%5Bstatus%5D=active&fields%5Busers%5D=name,age",
+                    "next": "/users?page%5Bnumber%5D=3&filter%5Bstatus%5D=active&fields%5Busers%5D=name,age",
+                    "last": "/users?page%5Bnumber%5D=3&filter%5Bstatus%5D=active&fields%5Busers%5D=name,age"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_api_document_cursor_links() {
+        let response = PaginatedResponse {
+            total: None,
+            total_pages: None,
+            next_cursor: Some("def".to_string()),
+            ..test_response(1, 0)
+        };
+        let document =
+            JsonApiDocument::from_response(&response, "users", &parse("page[cursor]=abc"), None)
+                .unwrap();
+        assert!(document.links.is_none());
+        assert_eq!(document.data[0].attributes.len(), 3);
+
+        let document = JsonApiDocument::from_response(
+            &response,
+            "users",
+            &parse("page[cursor]=abc"),
+            Some("/users"),
+        )
+        .unwrap();
+        let links = document.links.unwrap();
+        assert_eq!(links.first, "/users");
+        assert_eq!(links.next, Some("/users?page%5Bcursor%5D=def".to_string()));
+        assert_eq!(links.last, None);
+    }
+}
@@ -1,9 +1,9 @@
 #[macro_export]
 macro_rules! paginated_query_as {
     ($query:expr) => {{
-        paginated_query_as($query)
+        $crate::paginated_query_as_for($query)
     }};
     ($type:ty, $query:expr) => {{
-        paginated_query_as::<$type>($query)
+        $crate::paginated_query_as_for::<$type, _>($query)
     }};
 }
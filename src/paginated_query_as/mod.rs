@@ -1,10 +1,27 @@
 mod builders;
+mod column_registry;
+mod error;
 mod examples;
+mod integrations;
 mod internal;
+mod json_api;
 mod r#macro;
 mod models;
+mod odata;
+mod pagination_limits;
+#[cfg(feature = "postgres")]
+mod postgres_search;
 mod utils;
 
 pub use builders::*;
+pub use column_registry::*;
+pub use error::*;
+#[allow(unused_imports)]
+pub use integrations::*;
+pub use json_api::*;
 pub use models::*;
+pub use odata::*;
+pub use pagination_limits::*;
+#[cfg(feature = "postgres")]
+pub use postgres_search::*;
 pub use utils::*;
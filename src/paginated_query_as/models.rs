@@ -1,8 +1,15 @@
 use crate::paginated_query_as::internal::{
-    QueryDateRangeParams, QueryPaginationParams, QuerySearchParams, QuerySortParams,
+    encode_query_pairs, escape_like_pattern, filter_expr_deserialize, filter_value_deserialize,
+    query_params_to_pairs, QueryDateRangeParams, QueryKeysetParams, QueryPaginationParams,
+    QuerySearchParams, QuerySortParams, DEFAULT_MAX_FILTER_EXPRESSION_DEPTH,
+    DEFAULT_MAX_FILTER_EXPRESSION_LEAVES, DEFAULT_PAGE,
 };
+use crate::PaginationError;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
 use std::collections::HashMap;
+use std::fmt;
 use std::marker::PhantomData;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -17,6 +24,129 @@ pub struct PaginatedResponse<T> {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_pages: Option<i64>,
+
+    /// `false` when the total is a planner estimate or capped by `TotalsMode::ExactUpTo`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_exact: Option<bool>,
+
+    #[serde(default)]
+    pub has_next_page: bool,
+
+    #[serde(default)]
+    pub has_previous_page: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prev_cursor: Option<String>,
+
+    /// URLs of the pages around this one, see `PaginatedQueryBuilder::with_links`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<PaginatedLinks>,
+}
+
+/// URLs of the first, previous, next and last pages of a paginated response.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PaginatedLinks {
+    pub first: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prev: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+    /// Only known to offset pagination with a total count.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last: Option<String>,
+}
+
+impl PaginatedLinks {
+    /// Returns the links as an RFC 8288 `Link` header value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx_paginated::PaginatedLinks;
+    ///
+    /// let links = PaginatedLinks {
+    ///     first: "/users".to_string(),
+    ///     prev: None,
+    ///     next: Some("/users?page=2".to_string()),
+    ///     last: None,
+    /// };
+    /// assert_eq!(
+    ///     links.to_link_header(),
+    ///     r#"</users>; rel="first", </users?page=2>; rel="next""#
+    /// );
+    /// ```
+    pub fn to_link_header(&self) -> String {
+        [
+            ("first", Some(&self.first)),
+            ("prev", self.prev.as_ref()),
+            ("next", self.next.as_ref()),
+            ("last", self.last.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(rel, url)| Some(format!("<{}>; rel=\"{}\"", url?, rel)))
+        .collect::<Vec<String>>()
+        .join(", ")
+    }
+
+    /// Builds the links around a response.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - Response the links point around
+    /// * `cursor_pagination` - Whether pages are reached through cursors rather than numbers
+    /// * `link` - Gives the URL of a page number, or of a cursor when one is given
+    pub(crate) fn from_response<T>(
+        response: &PaginatedResponse<T>,
+        cursor_pagination: bool,
+        link: impl Fn(i64, Option<&str>) -> String,
+    ) -> Self {
+        if cursor_pagination {
+            return Self {
+                first: link(DEFAULT_PAGE, None),
+                prev: response
+                    .prev_cursor
+                    .as_deref()
+                    .map(|cursor| link(DEFAULT_PAGE, Some(cursor))),
+                next: response
+                    .next_cursor
+                    .as_deref()
+                    .map(|cursor| link(DEFAULT_PAGE, Some(cursor))),
+                last: None,
+            };
+        }
+
+        let page = response
+            .pagination
+            .as_ref()
+            .map_or(DEFAULT_PAGE, |pagination| pagination.page);
+        Self {
+            first: link(DEFAULT_PAGE, None),
+            prev: response.has_previous_page.then(|| link(page - 1, None)),
+            next: response.has_next_page.then(|| link(page + 1, None)),
+            last: response
+                .total_pages
+                .filter(|total_pages| *total_pages > 0)
+                .map(|total_pages| link(total_pages, None)),
+        }
+    }
+}
+
+/// How `PaginatedQueryBuilder` calculates the total record count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TotalsMode {
+    /// Counts every matching record with `COUNT(*)`.
+    #[default]
+    Exact,
+    /// Reads the planner's row estimate of the filtered query, e.g. `EXPLAIN (FORMAT JSON)`
+    /// on PostgreSQL. Counts exactly on databases without an estimate.
+    Estimated,
+    /// Counts exactly up to the given number of records, the total is capped past it.
+    ExactUpTo(i64),
+    /// Skips the count, the response carries no total.
+    None,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -30,27 +160,168 @@ pub struct FlatQueryParams {
     #[serde(flatten)]
     pub date_range: Option<QueryDateRangeParams>,
     #[serde(flatten)]
+    pub keyset: Option<QueryKeysetParams>,
+    pub cursor: Option<String>,
+    /// Filter expression, given as JSON, e.g. `filter={"or":[...]}` in query strings.
+    #[serde(
+        default,
+        deserialize_with = "filter_expr_deserialize",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filter: Option<FilterExpr>,
+    #[serde(flatten)]
     pub filters: Option<HashMap<String, Option<String>>>,
 }
 
-#[derive(Default, Clone)]
 pub struct QueryParams<'q, T> {
     pub pagination: QueryPaginationParams,
     pub sort: QuerySortParams,
     pub search: QuerySearchParams,
     pub date_range: QueryDateRangeParams,
+    pub keyset: QueryKeysetParams,
+    pub cursor: Option<String>,
     pub filters: HashMap<String, Option<String>>,
+    /// Filter expression, combined with `AND` with the filters.
+    pub filter: Option<FilterExpr>,
     pub(crate) _phantom: PhantomData<&'q T>,
 }
 
+// Implemented by hand so models don't need to implement `Default` themselves
+impl<T> Default for QueryParams<'_, T> {
+    fn default() -> Self {
+        Self {
+            pagination: Default::default(),
+            sort: Default::default(),
+            search: Default::default(),
+            date_range: Default::default(),
+            keyset: Default::default(),
+            cursor: None,
+            filters: HashMap::new(),
+            filter: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+// Implemented by hand so models don't need to implement `Clone` themselves
+impl<T> Clone for QueryParams<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            pagination: self.pagination.clone(),
+            sort: self.sort.clone(),
+            search: self.search.clone(),
+            date_range: self.date_range.clone(),
+            keyset: self.keyset.clone(),
+            cursor: self.cursor.clone(),
+            filters: self.filters.clone(),
+            filter: self.filter.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+// Implemented by hand so models don't need to implement `Debug` themselves
+impl<T> fmt::Debug for QueryParams<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueryParams")
+            .field("pagination", &self.pagination)
+            .field("sort", &self.sort)
+            .field("search", &self.search)
+            .field("date_range", &self.date_range)
+            .field("keyset", &self.keyset)
+            .field("cursor", &self.cursor)
+            .field("filters", &self.filters)
+            .field("filter", &self.filter)
+            .finish()
+    }
+}
+
+// Implemented by hand so models don't need to implement `PartialEq` themselves
+impl<T> PartialEq for QueryParams<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pagination == other.pagination
+            && self.sort == other.sort
+            && self.search == other.search
+            && self.date_range == other.date_range
+            && self.keyset == other.keyset
+            && self.cursor == other.cursor
+            && self.filters == other.filters
+            && self.filter == other.filter
+    }
+}
+
+impl<T> QueryParams<'_, T> {
+    /// Serializes the params back into the query string they are parsed from.
+    ///
+    /// # Details
+    ///
+    /// - Parameters come in a fixed order: `page`, `page_size`, `sort`, `search`,
+    ///   `search_columns`, `search_mode`, `date_after`, `date_before`, `date_column`,
+    ///   `after_sort_value`, `after_tie_breaker_value`, `cursor`, `filter` as JSON, then the
+    ///   filters sorted by key
+    /// - Parameters left to their default and filters without a value are left out
+    /// - The sort is always given as `sort=-created_at,name`
+    /// - Equal params give the same query string, e.g. for cache keys or saved views
+    ///
+    /// Parsing the query string as `FlatQueryParams` gives back equal params, except for what
+    /// query strings can't express or parsing normalizes:
+    ///
+    /// - An unset `date_column` or `search_columns` parses as the default column or no columns
+    /// - Filters without a value are left out
+    /// - A `page` below 1 parses as 1, and a `page_size` out of the page size limits is clamped
+    /// - Search text keeps letters, digits, spaces and `-` only, with whitespace trimmed and
+    ///   collapsed, and is cut to 100 characters
+    /// - Sort and search columns are trimmed and split on `,`, and a sort column starting with
+    ///   `+` loses it when ascending
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryFilterOperator, QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String,
+    ///     status: String,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_pagination(2, 20)
+    ///     .with_sort("name", QuerySortDirection::Ascending)
+    ///     .with_filter_operator("status", QueryFilterOperator::In, Some("active,pending"))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     params.to_query_string(),
+    ///     "page=2&page_size=20&sort=name&status%5Bin%5D=active,pending"
+    /// );
+    /// ```
+    pub fn to_query_string(&self) -> String {
+        encode_query_pairs(&query_params_to_pairs(self))
+    }
+}
+
 impl<'q, T> From<FlatQueryParams> for QueryParams<'q, T> {
     fn from(params: FlatQueryParams) -> Self {
         QueryParams {
             pagination: params.pagination.unwrap_or_default(),
-            sort: params.sort.unwrap_or_default(),
+            sort: params
+                .sort
+                .map(|sort| {
+                    if sort.sorts.is_empty() {
+                        sort
+                    } else {
+                        QuerySortParams::from_terms(sort.sorts)
+                    }
+                })
+                .unwrap_or_default(),
             search: params.search.unwrap_or_default(),
             date_range: params.date_range.unwrap_or_default(),
+            keyset: params.keyset.unwrap_or_default(),
+            cursor: params.cursor,
             filters: params.filters.unwrap_or_default(),
+            filter: params.filter,
             _phantom: PhantomData::<&'q T>,
         }
     }
@@ -63,3 +334,420 @@ pub enum QuerySortDirection {
     #[default]
     Descending,
 }
+
+/// How the search text matches the search columns, ignoring case.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuerySearchMode {
+    /// Columns containing the search text.
+    #[default]
+    Contains,
+    /// Columns starting with the search text.
+    StartsWith,
+    /// Columns ending with the search text.
+    EndsWith,
+    /// Columns equal to the search text.
+    Exact,
+    /// Columns containing the search text as whole words, delimited by spaces.
+    WholeWord,
+}
+
+impl QuerySearchMode {
+    /// Name of the mode as written in the `search_mode` parameter, e.g. `starts_with`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuerySearchMode::Contains => "contains",
+            QuerySearchMode::StartsWith => "starts_with",
+            QuerySearchMode::EndsWith => "ends_with",
+            QuerySearchMode::Exact => "exact",
+            QuerySearchMode::WholeWord => "whole_word",
+        }
+    }
+
+    /// Renders the LIKE pattern of the search text, its wildcards escaped.
+    ///
+    /// Whole words are matched against the column wrapped in spaces.
+    pub(crate) fn like_pattern(&self, search: &str) -> String {
+        let search = escape_like_pattern(search);
+
+        match self {
+            QuerySearchMode::Contains => format!("%{}%", search),
+            QuerySearchMode::StartsWith => format!("{}%", search),
+            QuerySearchMode::EndsWith => format!("%{}", search),
+            QuerySearchMode::Exact => search,
+            QuerySearchMode::WholeWord => format!("% {} %", search),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryFilterOperator {
+    #[default]
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Like,
+    Ilike,
+    In,
+    NotIn,
+    IsNull,
+    IsNotNull,
+    Between,
+}
+
+impl QueryFilterOperator {
+    /// Name of the operator as written in filter keys, e.g. `price[gte]` or `price__gte`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QueryFilterOperator::Eq => "eq",
+            QueryFilterOperator::Ne => "ne",
+            QueryFilterOperator::Gt => "gt",
+            QueryFilterOperator::Gte => "gte",
+            QueryFilterOperator::Lt => "lt",
+            QueryFilterOperator::Lte => "lte",
+            QueryFilterOperator::Like => "like",
+            QueryFilterOperator::Ilike => "ilike",
+            QueryFilterOperator::In => "in",
+            QueryFilterOperator::NotIn => "not_in",
+            QueryFilterOperator::IsNull => "is_null",
+            QueryFilterOperator::IsNotNull => "is_not_null",
+            QueryFilterOperator::Between => "between",
+        }
+    }
+
+    /// Parses an operator name, returning `None` for unknown operators.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "eq" => Some(QueryFilterOperator::Eq),
+            "ne" => Some(QueryFilterOperator::Ne),
+            "gt" => Some(QueryFilterOperator::Gt),
+            "gte" => Some(QueryFilterOperator::Gte),
+            "lt" => Some(QueryFilterOperator::Lt),
+            "lte" => Some(QueryFilterOperator::Lte),
+            "like" => Some(QueryFilterOperator::Like),
+            "ilike" => Some(QueryFilterOperator::Ilike),
+            "in" => Some(QueryFilterOperator::In),
+            "not_in" => Some(QueryFilterOperator::NotIn),
+            "is_null" => Some(QueryFilterOperator::IsNull),
+            "is_not_null" => Some(QueryFilterOperator::IsNotNull),
+            "between" => Some(QueryFilterOperator::Between),
+            _ => None,
+        }
+    }
+}
+
+/// Tree of filter conditions combined with `AND`, `OR` and `NOT`.
+///
+/// Leaves take the same operators and values as filter keys, e.g. comma separated values
+/// for `in` and `between`.
+///
+/// # JSON
+///
+/// Groups are keyed by `and`, `or` and `not`, and leaves are objects with a `column`, an
+/// `operator` defaulting to `eq` and a `value`, which may be a number, a boolean, or an
+/// array for `in`, `not_in` and `between`:
+///
+/// ```json
+/// {"and": [
+///   {"or": [{"column": "status", "value": "active"}, {"column": "status", "value": "trial"}]},
+///   {"not": {"column": "region", "operator": "in", "value": ["US", "CA"]}}
+/// ]}
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterExpr {
+    And(Vec<FilterExpr>),
+    Or(Vec<FilterExpr>),
+    Not(Box<FilterExpr>),
+    #[serde(untagged)]
+    Leaf {
+        column: String,
+        #[serde(default)]
+        operator: QueryFilterOperator,
+        #[serde(
+            default,
+            deserialize_with = "filter_value_deserialize",
+            skip_serializing_if = "Option::is_none"
+        )]
+        value: Option<String>,
+    },
+}
+
+impl FilterExpr {
+    /// Creates a condition on a column.
+    pub fn leaf(
+        column: impl Into<String>,
+        operator: QueryFilterOperator,
+        value: Option<impl Into<String>>,
+    ) -> Self {
+        FilterExpr::Leaf {
+            column: column.into(),
+            operator,
+            value: value.map(Into::into),
+        }
+    }
+
+    /// Returns the columns of the leaves, in the order they're written.
+    pub fn columns(&self) -> Vec<&str> {
+        match self {
+            FilterExpr::And(exprs) | FilterExpr::Or(exprs) => {
+                exprs.iter().flat_map(FilterExpr::columns).collect()
+            }
+            FilterExpr::Not(expr) => expr.columns(),
+            FilterExpr::Leaf { column, .. } => vec![column.as_str()],
+        }
+    }
+
+    /// Checks the nesting depth and the number of leaves, so that untrusted input can't
+    /// overflow the stack or bind an unbounded number of values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaginationError::InvalidExpression` when the expression is nested deeper than
+    /// the OData parser allows or has too many leaves
+    pub(crate) fn check_limits(&self) -> Result<(), PaginationError> {
+        fn visit(
+            expr: &FilterExpr,
+            depth: usize,
+            leaves: &mut usize,
+        ) -> Result<(), PaginationError> {
+            if depth > DEFAULT_MAX_FILTER_EXPRESSION_DEPTH {
+                return Err(PaginationError::InvalidExpression(
+                    "expression is nested too deeply".to_string(),
+                ));
+            }
+            match expr {
+                FilterExpr::And(exprs) | FilterExpr::Or(exprs) => exprs
+                    .iter()
+                    .try_for_each(|expr| visit(expr, depth + 1, leaves)),
+                FilterExpr::Not(expr) => visit(expr, depth + 1, leaves),
+                FilterExpr::Leaf { .. } => {
+                    *leaves += 1;
+                    if *leaves > DEFAULT_MAX_FILTER_EXPRESSION_LEAVES {
+                        return Err(PaginationError::InvalidExpression(format!(
+                            "expression has more than {} conditions",
+                            DEFAULT_MAX_FILTER_EXPRESSION_LEAVES
+                        )));
+                    }
+                    Ok(())
+                }
+            }
+        }
+
+        visit(self, 0, &mut 0)
+    }
+}
+
+/// Declared SQL type of a column, driving how filter values are validated and cast.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnType {
+    Text,
+    SmallInt,
+    Integer,
+    BigInt,
+    Real,
+    Double,
+    Numeric,
+    Boolean,
+    Uuid,
+    Date,
+    Time,
+    Timestamp,
+    TimestampTz,
+    Json,
+}
+
+impl ColumnType {
+    /// Name of the type as written in `#[paginated(sql_type = "...")]`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColumnType::Text => "text",
+            ColumnType::SmallInt => "smallint",
+            ColumnType::Integer => "integer",
+            ColumnType::BigInt => "bigint",
+            ColumnType::Real => "real",
+            ColumnType::Double => "double",
+            ColumnType::Numeric => "numeric",
+            ColumnType::Boolean => "boolean",
+            ColumnType::Uuid => "uuid",
+            ColumnType::Date => "date",
+            ColumnType::Time => "time",
+            ColumnType::Timestamp => "timestamp",
+            ColumnType::TimestampTz => "timestamptz",
+            ColumnType::Json => "json",
+        }
+    }
+
+    /// Parses a type name, returning `None` for unknown types.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "text" => Some(ColumnType::Text),
+            "smallint" => Some(ColumnType::SmallInt),
+            "integer" => Some(ColumnType::Integer),
+            "bigint" => Some(ColumnType::BigInt),
+            "real" => Some(ColumnType::Real),
+            "double" => Some(ColumnType::Double),
+            "numeric" => Some(ColumnType::Numeric),
+            "boolean" => Some(ColumnType::Boolean),
+            "uuid" => Some(ColumnType::Uuid),
+            "date" => Some(ColumnType::Date),
+            "time" => Some(ColumnType::Time),
+            "timestamp" => Some(ColumnType::Timestamp),
+            "timestamptz" => Some(ColumnType::TimestampTz),
+            "json" => Some(ColumnType::Json),
+            _ => None,
+        }
+    }
+
+    /// Validates a value against the type and returns the text to bind.
+    ///
+    /// # Returns
+    ///
+    /// Returns the value in a form every supported database casts to the type
+    /// (e.g. booleans as `1` or `0`), or `None` when it doesn't parse for the type
+    pub fn parse_value(&self, value: &str) -> Option<String> {
+        let trimmed = value.trim();
+        let parses_as_date_time = |formats: &[&str]| {
+            formats
+                .iter()
+                .any(|format| NaiveDateTime::parse_from_str(trimmed, format).is_ok())
+        };
+
+        match self {
+            ColumnType::Text => Some(value.to_string()),
+            ColumnType::SmallInt => trimmed.parse::<i16>().ok().map(|v| v.to_string()),
+            ColumnType::Integer => trimmed.parse::<i32>().ok().map(|v| v.to_string()),
+            ColumnType::BigInt => trimmed.parse::<i64>().ok().map(|v| v.to_string()),
+            ColumnType::Real | ColumnType::Double => trimmed
+                .parse::<f64>()
+                .is_ok_and(f64::is_finite)
+                .then(|| trimmed.to_string()),
+            ColumnType::Numeric => {
+                let digits = trimmed.strip_prefix(['-', '+']).unwrap_or(trimmed);
+                let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+                let is_numeric = !(integer.is_empty() && fraction.is_empty())
+                    && integer.chars().all(|c| c.is_ascii_digit())
+                    && fraction.chars().all(|c| c.is_ascii_digit());
+
+                is_numeric.then(|| trimmed.to_string())
+            }
+            ColumnType::Boolean => match trimmed.to_lowercase().as_str() {
+                "true" | "t" | "1" => Some("1".to_string()),
+                "false" | "f" | "0" => Some("0".to_string()),
+                _ => None,
+            },
+            ColumnType::Uuid => Uuid::parse_str(trimmed)
+                .ok()
+                .map(|uuid| uuid.hyphenated().to_string()),
+            ColumnType::Date => NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+                .is_ok()
+                .then(|| trimmed.to_string()),
+            ColumnType::Time => ["%H:%M:%S%.f", "%H:%M"]
+                .iter()
+                .any(|format| NaiveTime::parse_from_str(trimmed, format).is_ok())
+                .then(|| trimmed.to_string()),
+            ColumnType::Timestamp => {
+                parses_as_date_time(&["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"])
+                    .then(|| trimmed.to_string())
+            }
+            ColumnType::TimestampTz => (DateTime::parse_from_rfc3339(trimmed).is_ok()
+                || parses_as_date_time(&["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]))
+            .then(|| trimmed.to_string()),
+            ColumnType::Json => serde_json::from_str::<serde_json::Value>(trimmed)
+                .is_ok()
+                .then(|| trimmed.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_mode_like_patterns() {
+        assert_eq!(
+            QuerySearchMode::Contains.like_pattern("50%_off"),
+            "%50!%!_off%"
+        );
+        assert_eq!(QuerySearchMode::StartsWith.like_pattern("jo"), "jo%");
+        assert_eq!(QuerySearchMode::EndsWith.like_pattern("son"), "%son");
+        assert_eq!(QuerySearchMode::Exact.like_pattern("a_b"), "a!_b");
+        assert_eq!(QuerySearchMode::WholeWord.like_pattern("john"), "% john %");
+        assert_eq!(
+            serde_json::from_str::<QuerySearchMode>("\"whole_word\"").unwrap(),
+            QuerySearchMode::WholeWord
+        );
+        assert_eq!(QuerySearchMode::StartsWith.as_str(), "starts_with");
+    }
+
+    #[test]
+    fn test_column_type_names() {
+        assert_eq!(
+            ColumnType::parse("TimestampTz"),
+            Some(ColumnType::TimestampTz)
+        );
+        assert_eq!(ColumnType::parse("varchar"), None);
+        assert_eq!(
+            ColumnType::parse(ColumnType::BigInt.as_str()),
+            Some(ColumnType::BigInt)
+        );
+    }
+
+    #[test]
+    fn test_column_type_parse_value() {
+        assert_eq!(
+            ColumnType::Text.parse_value("01234"),
+            Some("01234".to_string())
+        );
+        assert_eq!(
+            ColumnType::Integer.parse_value(" +42"),
+            Some("42".to_string())
+        );
+        assert_eq!(ColumnType::SmallInt.parse_value("40000"), None);
+        assert_eq!(ColumnType::BigInt.parse_value("john"), None);
+        assert_eq!(
+            ColumnType::Double.parse_value("3.14"),
+            Some("3.14".to_string())
+        );
+        assert_eq!(ColumnType::Double.parse_value("NaN"), None);
+        assert_eq!(
+            ColumnType::Numeric.parse_value("-12.50"),
+            Some("-12.50".to_string())
+        );
+        assert_eq!(ColumnType::Numeric.parse_value("1e5"), None);
+        assert_eq!(
+            ColumnType::Boolean.parse_value("TRUE"),
+            Some("1".to_string())
+        );
+        assert_eq!(ColumnType::Boolean.parse_value("f"), Some("0".to_string()));
+        assert_eq!(ColumnType::Boolean.parse_value("yes"), None);
+        assert_eq!(
+            ColumnType::Uuid.parse_value("550E8400E29B41D4A716446655440000"),
+            Some("550e8400-e29b-41d4-a716-446655440000".to_string())
+        );
+        assert_eq!(ColumnType::Date.parse_value("2024-02-30"), None);
+        assert_eq!(
+            ColumnType::Time.parse_value("12:30"),
+            Some("12:30".to_string())
+        );
+        assert!(ColumnType::Timestamp
+            .parse_value("2024-01-01T12:34:56")
+            .is_some());
+        assert!(ColumnType::TimestampTz
+            .parse_value("2024-01-01T12:34:56+02:00")
+            .is_some());
+        assert_eq!(ColumnType::Json.parse_value("{invalid"), None);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::paginated_query_as::internal::{
+    default_page_size, parse_odata_filter, parse_odata_orderby, QueryPaginationParams,
+    QuerySortParams, QuerySortTerm, DEFAULT_MAX_PAGE_SIZE, DEFAULT_PAGE,
+};
+use crate::{FilterExpr, PaginationError, QueryParams};
+use serde::Deserialize;
+
+/// Query parameters in the OData syntax, an alternative to `FlatQueryParams`.
+///
+/// | Parameter                  | Maps to                                  |
+/// |----------------------------|------------------------------------------|
+/// | `$filter=price gt 10`      | `filter`, the filter expression          |
+/// | `$orderby=price desc,name` | `sort=-price,name`                       |
+/// | `$top=20`                  | `page_size=20`                           |
+/// | `$skip=40`                 | `page=3` with `$top=20`                  |
+///
+/// # Details
+///
+/// - Parameters are parsed while deserializing, failing with the message of
+///   `PaginationError::InvalidExpression` on malformed expressions or unsupported functions
+/// - `$top` is the page size as sent, from 1 up to the largest page size, so `$skip` maps onto
+///   a page of that size. Larger values fail instead of being clamped as `page_size` is, see
+///   `PaginationLimits` for a lower limit
+/// - `$skip` must be a multiple of the page size, as pages are fetched with `page`
+/// - Other parameters, e.g. `$select` or `$count`, are ignored
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx::Postgres;
+/// use serde::{Serialize};
+/// use sqlx_paginated::{ODataQueryParams, QueryBuilder, QueryParams};
+///
+/// #[derive(Serialize, Default)]
+/// struct ProductExample {
+///     status: String,
+///     price: i64,
+/// }
+///
+/// let odata_params: ODataQueryParams = serde_urlencoded::from_str(
+///     "$filter=price gt 10 and (status eq 'active' or status eq 'trial')\
+///      &$orderby=price desc&$top=20&$skip=40",
+/// )
+/// .unwrap();
+/// let params: QueryParams<ProductExample> = odata_params.into();
+///
+/// assert_eq!(params.pagination.page, 3);
+/// assert_eq!(params.pagination.page_size, 20);
+/// assert_eq!(params.sort.sort_column, "price");
+///
+/// let (conditions, _) = QueryBuilder::<ProductExample, Postgres>::new()
+///     .with_filters(&params)
+///     .build();
+///
+/// assert_eq!(
+///     conditions,
+///     vec!["(\"price\" > $1::smallint AND (\"status\" = $2 OR \"status\" = $3))"]
+/// );
+/// ```
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(try_from = "ODataRawQueryParams")]
+pub struct ODataQueryParams {
+    pub pagination: QueryPaginationParams,
+    pub sorts: Vec<QuerySortTerm>,
+    /// The parsed `$filter`, its columns validated when the query is built.
+    pub filter: Option<FilterExpr>,
+}
+
+#[derive(Deserialize)]
+struct ODataRawQueryParams {
+    #[serde(rename = "$filter")]
+    filter: Option<String>,
+    #[serde(rename = "$orderby")]
+    orderby: Option<String>,
+    #[serde(rename = "$top")]
+    top: Option<String>,
+    #[serde(rename = "$skip", default)]
+    skip: i64,
+}
+
+impl TryFrom<ODataRawQueryParams> for ODataQueryParams {
+    type Error = PaginationError;
+
+    fn try_from(raw: ODataRawQueryParams) -> Result<Self, Self::Error> {
+        let top = match raw.top.as_deref().map(str::trim) {
+            None => default_page_size(),
+            Some(top) => top
+                .parse::<i64>()
+                .ok()
+                .filter(|top| (1..=DEFAULT_MAX_PAGE_SIZE).contains(top))
+                .ok_or_else(|| {
+                    PaginationError::InvalidExpression(format!(
+                        "$top must be a page size from 1 to {}, got '{}'",
+                        DEFAULT_MAX_PAGE_SIZE, top
+                    ))
+                })?,
+        };
+        if raw.skip < 0 || raw.skip % top != 0 {
+            return Err(PaginationError::InvalidExpression(format!(
+                "$skip must be a multiple of the page size {}",
+                top
+            )));
+        }
+
+        Ok(Self {
+            pagination: QueryPaginationParams {
+                page: raw.skip / top + DEFAULT_PAGE,
+                page_size: top,
+            },
+            sorts: raw
+                .orderby
+                .as_deref()
+                .map(parse_odata_orderby)
+                .transpose()?
+                .unwrap_or_default(),
+            filter: raw
+                .filter
+                .as_deref()
+                .filter(|filter| !filter.trim().is_empty())
+                .map(parse_odata_filter)
+                .transpose()?,
+        })
+    }
+}
+
+impl<'q, T> From<ODataQueryParams> for QueryParams<'q, T> {
+    fn from(params: ODataQueryParams) -> Self {
+        QueryParams {
+            pagination: params.pagination,
+            sort: QuerySortParams::from_terms(params.sorts),
+            filter: params.filter,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QueryFilterOperator, QuerySortDirection};
+
+    #[test]
+    fn test_odata_query_params() {
+        let params: ODataQueryParams = serde_urlencoded::from_str(
+            "$filter=name eq 'john'&$orderby=created_at desc, name&$top=25&$skip=50&$count=true",
+        )
+        .unwrap();
+
+        assert_eq!(
+            params,
+            ODataQueryParams {
+                pagination: QueryPaginationParams {
+                    page: 3,
+                    page_size: 25,
+                },
+                sorts: vec![
+                    QuerySortTerm::new("created_at", QuerySortDirection::Descending),
+                    QuerySortTerm::new("name", QuerySortDirection::Ascending),
+                ],
+                filter: Some(FilterExpr::leaf(
+                    "name",
+                    QueryFilterOperator::Eq,
+                    Some("john")
+                )),
+            }
+        );
+        assert_eq!(
+            serde_urlencoded::from_str::<ODataQueryParams>("").unwrap(),
+            ODataQueryParams::default()
+        );
+    }
+
+    #[test]
+    fn test_odata_small_top() {
+        // The page size sent is the one $skip is checked against, so it isn't raised
+        let params: ODataQueryParams = serde_urlencoded::from_str("$top=5&$skip=10").unwrap();
+        assert_eq!(
+            params.pagination,
+            QueryPaginationParams {
+                page: 3,
+                page_size: 5,
+            }
+        );
+
+        let params: ODataQueryParams = serde_urlencoded::from_str("$top=1").unwrap();
+        assert_eq!(params.pagination.page_size, 1);
+    }
+
+    #[test]
+    fn test_odata_query_params_rejected() {
+        let error = serde_urlencoded::from_str::<ODataQueryParams>("$filter=length(name) gt 3")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Invalid expression: unsupported function: length"
+        );
+
+        let error = serde_urlencoded::from_str::<ODataQueryParams>("$top=20&$skip=30")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Invalid expression: $skip must be a multiple of the page size 20"
+        );
+
+        let error = serde_urlencoded::from_str::<ODataQueryParams>("$top=0")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Invalid expression: $top must be a page size from 1 to 10000, got '0'"
+        );
+
+        let error = serde_urlencoded::from_str::<ODataQueryParams>("$top=20000")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Invalid expression: $top must be a page size from 1 to 10000, got '20000'"
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::paginated_query_as::internal::{default_search_columns, parse_filter_key};
+use crate::{ColumnRegistry, FilterExpr, PaginationError, QueryParams};
+
+/// Limits applied to the query parameters received by an endpoint, before any query is built.
+///
+/// By default nothing is limited beyond the parsing of the parameters. Web framework
+/// integrations read the limits of the route they extract the parameters for.
+#[derive(Debug, Clone, Default)]
+pub struct PaginationLimits {
+    max_page_size: Option<i64>,
+    column_registry: Option<ColumnRegistry>,
+}
+
+impl PaginationLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rejects page sizes above the given maximum.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_page_size` - Largest page size accepted
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_max_page_size(mut self, max_page_size: i64) -> Self {
+        self.max_page_size = Some(max_page_size);
+        self
+    }
+
+    /// Rejects sort, filter and search columns the registry doesn't allow them for.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - Columns accepted, e.g. `T::column_registry()` for a `PaginatedModel`
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_column_registry(mut self, registry: ColumnRegistry) -> Self {
+        self.column_registry = Some(registry);
+        self
+    }
+
+    /// Checks the query parameters against the limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Query parameters received by the endpoint
+    ///
+    /// # Details
+    ///
+    /// - Page sizes above the maximum fail with `PaginationError::InvalidPageSize`
+    /// - Sort columns that aren't sortable fail with `PaginationError::InvalidSortColumn`
+    /// - Filter, filter expression and search columns that aren't filterable or searchable
+    ///   fail with `PaginationError::InvalidColumn`
+    /// - The default sort and search columns aren't checked, as clients didn't send them
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{ColumnRegistry, PaginationError, PaginationLimits, QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let limits = PaginationLimits::new()
+    ///     .with_max_page_size(50)
+    ///     .with_column_registry(ColumnRegistry::from_struct_fields::<UserExample>());
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_sort("password", QuerySortDirection::Ascending)
+    ///     .build();
+    ///
+    /// assert!(matches!(
+    ///     limits.validate(&params),
+    ///     Err(PaginationError::InvalidSortColumn(column)) if column == "password"
+    /// ));
+    /// ```
+    pub fn validate<T>(&self, params: &QueryParams<T>) -> Result<(), PaginationError> {
+        if let Some(max_page_size) = self.max_page_size {
+            if params.pagination.page_size > max_page_size {
+                return Err(PaginationError::InvalidPageSize {
+                    page_size: params.pagination.page_size,
+                    max_page_size,
+                });
+            }
+        }
+
+        let Some(registry) = &self.column_registry else {
+            return Ok(());
+        };
+
+        if !params.sort.is_default_sort_column() {
+            if let Some(term) = params
+                .sort
+                .get_sort_terms()
+                .into_iter()
+                .find(|term| !registry.is_sortable(&term.column))
+            {
+                return Err(PaginationError::InvalidSortColumn(term.column));
+            }
+        }
+
+        for key in params.filters.keys() {
+            match parse_filter_key(key) {
+                Some((column, _)) if registry.is_filterable(column) => {}
+                _ => return Err(PaginationError::InvalidColumn(key.clone())),
+            }
+        }
+        if let Some(column) = params
+            .filter
+            .iter()
+            .flat_map(FilterExpr::columns)
+            .find(|column| !registry.is_filterable(column))
+        {
+            return Err(PaginationError::InvalidColumn(column.to_string()));
+        }
+
+        let has_search = params
+            .search
+            .search
+            .as_ref()
+            .is_some_and(|search| !search.trim().is_empty());
+        if has_search && params.search.search_columns != default_search_columns() {
+            if let Some(column) = params
+                .search
+                .search_columns
+                .iter()
+                .flatten()
+                .find(|column| !registry.is_searchable(column))
+            {
+                return Err(PaginationError::InvalidColumn(column.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FlatQueryParams, QueryParamsBuilder};
+    use serde::Serialize;
+
+    #[derive(Default, Serialize)]
+    struct TestModel {
+        name: String,
+        status: String,
+    }
+
+    fn test_limits() -> PaginationLimits {
+        PaginationLimits::new()
+            .with_max_page_size(50)
+            .with_column_registry(ColumnRegistry::from_struct_fields::<TestModel>())
+    }
+
+    fn test_params(query: serde_json::Value) -> QueryParams<'static, TestModel> {
+        serde_json::from_value::<FlatQueryParams>(query)
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn test_validate_accepts_defaults() {
+        let params = QueryParamsBuilder::<TestModel>::new().build();
+
+        assert!(test_limits().validate(&params).is_ok());
+        assert!(PaginationLimits::new().validate(&params).is_ok());
+    }
+
+    #[test]
+    fn test_validate_page_size() {
+        let params = test_params(serde_json::json!({ "page_size": "100" }));
+
+        assert!(matches!(
+            test_limits().validate(&params),
+            Err(PaginationError::InvalidPageSize {
+                page_size: 100,
+                max_page_size: 50
+            })
+        ));
+    }
+
+    #[test]
+    fn test_validate_columns() {
+        let params =
+            test_params(serde_json::json!({ "status[in]": "active,banned", "name": "john" }));
+        assert!(test_limits().validate(&params).is_ok());
+
+        let params = test_params(serde_json::json!({ "password": "secret" }));
+        assert!(matches!(
+            test_limits().validate(&params),
+            Err(PaginationError::InvalidColumn(column)) if column == "password"
+        ));
+
+        let params =
+            test_params(serde_json::json!({ "search": "john", "search_columns": "name,email" }));
+        assert!(matches!(
+            test_limits().validate(&params),
+            Err(PaginationError::InvalidColumn(column)) if column == "email"
+        ));
+
+        let params = test_params(serde_json::json!({
+            "filter": r#"{"or": [{"column": "status", "value": "active"}, {"column": "password", "value": "x"}]}"#,
+        }));
+        assert!(matches!(
+            test_limits().validate(&params),
+            Err(PaginationError::InvalidColumn(column)) if column == "password"
+        ));
+
+        let params = test_params(serde_json::json!({ "sort": "-status,created_at" }));
+        assert!(matches!(
+            test_limits().validate(&params),
+            Err(PaginationError::InvalidSortColumn(column)) if column == "created_at"
+        ));
+    }
+}
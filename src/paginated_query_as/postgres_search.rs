@@ -0,0 +1,266 @@
+use crate::paginated_query_as::internal::{
+    get_postgres_search_query, get_postgres_search_vector, get_postgres_trigram_condition,
+    get_postgres_trigram_similarity, QueryDialect,
+};
+
+/// PostgreSQL full-text search, replacing the `LIKE` matching of the `search` parameter.
+///
+/// # Details
+///
+/// - The search text is parsed with `websearch_to_tsquery`, so quoted phrases, `or` and
+///   `-word` exclusions are supported and malformed input never fails the query
+/// - Without a vector column, the search columns are cast to text and joined with NULLs as
+///   empty text: `to_tsvector('english', coalesce("name"::text, '') || ' ' || ...)`
+/// - The configuration is rendered as a literal rather than bound, so the search can use an
+///   expression index built over the same expression
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx::Postgres;
+/// use serde::{Serialize};
+/// use sqlx_paginated::{FullTextSearch, QueryBuilder, QueryParamsBuilder};
+///
+/// #[derive(Serialize, Default)]
+/// struct ArticleExample {
+///     title: String,
+///     body: String,
+/// }
+///
+/// let params = QueryParamsBuilder::<ArticleExample>::new()
+///     .with_search("rust -java", vec!["title", "body"])
+///     .build();
+/// let (conditions, _) = QueryBuilder::<ArticleExample, Postgres>::new()
+///     .with_full_text_search(FullTextSearch::new("english"))
+///     .with_search(&params)
+///     .build();
+///
+/// assert_eq!(
+///     conditions,
+///     vec!["to_tsvector('english', coalesce(\"title\"::text, '') || ' ' || coalesce(\"body\"::text, '')) @@ websearch_to_tsquery('english', $1)"]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FullTextSearch {
+    config: String,
+    vector_column: Option<String>,
+    rank_sort: bool,
+}
+
+impl FullTextSearch {
+    /// Creates a full-text search over the search columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Text search configuration, e.g. `english` or `simple`
+    pub fn new(config: impl Into<String>) -> Self {
+        Self {
+            config: config.into(),
+            vector_column: None,
+            rank_sort: false,
+        }
+    }
+
+    /// Matches a precomputed `tsvector` column instead of the search columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The `tsvector` column of the query, e.g. a generated `search_vector` column
+    ///
+    /// # Details
+    ///
+    /// The column isn't checked against the column registry, as it's usually not a field of
+    /// the model. The search columns of the params are ignored.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_vector_column(mut self, column: impl Into<String>) -> Self {
+        self.vector_column = Some(column.into());
+        self
+    }
+
+    /// Orders the results by `ts_rank`, most relevant first, when no explicit sort is given.
+    ///
+    /// # Details
+    ///
+    /// Only applies to `PaginatedQueryBuilder` with offset pagination, the default sort
+    /// breaking ties between equally ranked rows.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_rank_sort(mut self) -> Self {
+        self.rank_sort = true;
+        self
+    }
+
+    /// Renders the `tsvector` searched, or `None` without a vector column or search columns.
+    fn search_vector(
+        &self,
+        dialect: &dyn QueryDialect,
+        quoted_columns: &[String],
+    ) -> Option<String> {
+        match &self.vector_column {
+            Some(column) => Some(dialect.quote_identifier(column)),
+            None if quoted_columns.is_empty() => None,
+            None => Some(get_postgres_search_vector(&self.config, quoted_columns)),
+        }
+    }
+}
+
+/// PostgreSQL trigram search with `pg_trgm`, replacing the `LIKE` matching of the `search`
+/// parameter with a fuzzy match tolerating typos.
+///
+/// # Details
+///
+/// - Each search column matches as text with `"name"::text % $1`, the columns combined with OR
+/// - The `%` operator uses the `pg_trgm.similarity_threshold` setting, 0.3 by default, and
+///   can use a GIN or GiST index with the `gin_trgm_ops` or `gist_trgm_ops` operator class
+/// - The `pg_trgm` extension must be installed: `CREATE EXTENSION pg_trgm`
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx::Postgres;
+/// use serde::{Serialize};
+/// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder, TrigramSearch};
+///
+/// #[derive(Serialize, Default)]
+/// struct CustomerExample {
+///     name: String,
+///     company: String,
+/// }
+///
+/// let params = QueryParamsBuilder::<CustomerExample>::new()
+///     .with_search("jhon", vec!["name", "company"])
+///     .build();
+/// let (conditions, _) = QueryBuilder::<CustomerExample, Postgres>::new()
+///     .with_trigram_search(TrigramSearch::new())
+///     .with_search(&params)
+///     .build();
+///
+/// assert_eq!(conditions, vec!["(\"name\"::text % $1 OR \"company\"::text % $1)"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrigramSearch {
+    threshold: Option<f64>,
+    rank_sort: bool,
+}
+
+impl TrigramSearch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches rows at least as similar to the search text as the threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Minimal `similarity` between 0 and 1, clamped to that range
+    ///
+    /// # Details
+    ///
+    /// The threshold filters the rows matched by `%`, which still applies and can use the
+    /// trigram index. Thresholds below `pg_trgm.similarity_threshold` match no more rows.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = Some(threshold.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Orders the results by `similarity`, most similar first, when no explicit sort is given.
+    ///
+    /// # Details
+    ///
+    /// Rows are ranked by their most similar search column. Only applies to
+    /// `PaginatedQueryBuilder` with offset pagination, the default sort breaking ties.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_rank_sort(mut self) -> Self {
+        self.rank_sort = true;
+        self
+    }
+}
+
+/// The PostgreSQL search replacing the `LIKE` matching of the `search` parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PostgresSearch {
+    FullText(FullTextSearch),
+    Trigram(TrigramSearch),
+}
+
+impl PostgresSearch {
+    /// Checks whether results are ordered by rank without an explicit sort.
+    pub(crate) fn rank_sort(&self) -> bool {
+        match self {
+            PostgresSearch::FullText(full_text_search) => full_text_search.rank_sort,
+            PostgresSearch::Trigram(trigram_search) => trigram_search.rank_sort,
+        }
+    }
+
+    /// Renders the condition matching the search text bound to the placeholder.
+    ///
+    /// # Arguments
+    ///
+    /// * `dialect` - Dialect quoting the vector column
+    /// * `quoted_columns` - The safe search columns, quoted
+    /// * `placeholder` - Placeholder of the search text
+    ///
+    /// # Returns
+    ///
+    /// Returns the condition, or `None` when there is nothing to search in
+    pub(crate) fn condition(
+        &self,
+        dialect: &dyn QueryDialect,
+        quoted_columns: &[String],
+        placeholder: &str,
+    ) -> Option<String> {
+        match self {
+            PostgresSearch::FullText(full_text_search) => Some(format!(
+                "{} @@ {}",
+                full_text_search.search_vector(dialect, quoted_columns)?,
+                get_postgres_search_query(&full_text_search.config, placeholder)
+            )),
+            PostgresSearch::Trigram(_) if quoted_columns.is_empty() => None,
+            PostgresSearch::Trigram(trigram_search) => Some(format!(
+                "({})",
+                quoted_columns
+                    .iter()
+                    .map(|column| get_postgres_trigram_condition(
+                        column,
+                        placeholder,
+                        trigram_search.threshold
+                    ))
+                    .collect::<Vec<String>>()
+                    .join(" OR ")
+            )),
+        }
+    }
+
+    /// Renders the relevance of a row to the search text bound to the placeholder, higher
+    /// being more relevant, see `condition` for the arguments.
+    pub(crate) fn rank(
+        &self,
+        dialect: &dyn QueryDialect,
+        quoted_columns: &[String],
+        placeholder: &str,
+    ) -> Option<String> {
+        match self {
+            PostgresSearch::FullText(full_text_search) => Some(format!(
+                "ts_rank({}, {})",
+                full_text_search.search_vector(dialect, quoted_columns)?,
+                get_postgres_search_query(&full_text_search.config, placeholder)
+            )),
+            PostgresSearch::Trigram(_) if quoted_columns.is_empty() => None,
+            PostgresSearch::Trigram(_) => {
+                Some(get_postgres_trigram_similarity(quoted_columns, placeholder))
+            }
+        }
+    }
+}
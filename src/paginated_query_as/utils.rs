@@ -1,11 +1,34 @@
+use crate::paginated_query_as::internal::QueryDatabase;
 use crate::PaginatedQueryBuilder;
+use chrono::{DateTime, Utc};
 use serde::Serialize;
+#[cfg(feature = "postgres")]
 use sqlx::postgres::PgArguments;
-use sqlx::{FromRow, Postgres};
+#[cfg(feature = "postgres")]
+use sqlx::Postgres;
+use sqlx::{Encode, FromRow, Type};
 
-pub fn paginated_query_as<'q, T>(sql: &'q str) -> PaginatedQueryBuilder<'q, T, PgArguments>
+/// Paginates a query on PostgreSQL, see `paginated_query_as_for` for the other databases.
+#[cfg(feature = "postgres")]
+pub fn paginated_query_as<'q, T>(
+    sql: &'q str,
+) -> PaginatedQueryBuilder<'q, T, Postgres, PgArguments>
 where
     T: for<'r> FromRow<'r, <Postgres as sqlx::Database>::Row> + Send + Unpin + Serialize + Default,
 {
     PaginatedQueryBuilder::new(sqlx::query_as::<_, T>(sql))
 }
+
+/// Paginates a query on the database of `DB`, inferred from the pool the query is fetched with.
+pub fn paginated_query_as_for<'q, T, DB>(
+    sql: &'q str,
+) -> PaginatedQueryBuilder<'q, T, DB, DB::Arguments<'q>>
+where
+    DB: QueryDatabase,
+    T: for<'r> FromRow<'r, DB::Row> + Send + Unpin + Serialize + Default,
+    DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    String: for<'a> Encode<'a, DB> + Type<DB>,
+    DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+{
+    PaginatedQueryBuilder::new(sqlx::query_as::<_, T>(sql))
+}